@@ -2,6 +2,7 @@ use std::env;
 
 use macrofied_toolbox::result;
 
+#[allow(clippy::disallowed_names)]
 fn main() -> Result<(), &'static str> {
     let break_test = env::args().collect::<Vec<_>>().last().unwrap_or(&String::new()) == "break";
 
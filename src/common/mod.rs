@@ -1,4 +1,6 @@
-pub use models::{Capture, Message, OnExpr, OnFail, OnSuccess, WhenExpr};
+pub use models::{Capture, Escape, Message, OnFail, OnSuccess, WhenExpr};
+#[cfg(feature = "result")]
+pub use models::{MapErr, OnFailArm, Retry};
 
 mod models;
 pub mod parse;
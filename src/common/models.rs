@@ -1,18 +1,18 @@
 #[cfg(feature = "trace")]
 use std::fmt::{self, Display, Formatter};
 
-use proc_macro2::TokenStream;
-#[cfg(feature = "trace")]
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
-use syn::{Expr, Lit};
+use syn::{Expr, Lifetime, LitStr, Pat, PatIdent};
 
 #[cfg(feature = "trace")]
 use crate::{display, displays};
 
+#[derive(Clone)]
 pub struct Message {
     pub args: Option<Vec<Expr>>,
     pub captured: Option<Capture>,
-    pub fmt: Lit,
+    pub fmt: LitStr,
 }
 
 #[cfg(feature = "trace")]
@@ -45,31 +45,123 @@ impl Message {
 
 #[derive(Clone)]
 pub struct Capture {
-    pub identifier: String,
-    pub mutable: bool,
-    pub reference: bool,
+    pub guard: Option<Expr>,
+    pub pattern: Pat,
 }
 
 impl<T: Into<String>> From<T> for Capture {
     fn from(identifier: T) -> Self {
         Self {
-            identifier: identifier.into(),
-            mutable: false,
-            reference: false,
+            guard: None,
+            pattern: Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: Ident::new(&identifier.into(), Span::call_site()),
+                subpat: None,
+            }),
         }
     }
 }
 
+impl PartialEq for Capture {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern.to_token_stream().to_string() == other.pattern.to_token_stream().to_string()
+    }
+}
+
 #[cfg(feature = "trace")]
 impl Display for Capture {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        write!(
-            fmt, "{{ identifier: {:?}, mutable: {}, reference: {} }}",
-            self.identifier, self.mutable, self.reference
-        )
+        let guard = self.guard.as_ref()
+            .map_or_else(|| String::from("None"), |guard| format!("\"{}\"", guard.to_token_stream()));
+
+        write!(fmt, "{{ guard: {guard}, pattern: \"{}\" }}", self.pattern.to_token_stream())
+    }
+}
+
+impl Capture {
+    #[cfg(feature = "result")]
+    pub fn is_declaration(&self) -> bool {
+        self.guard.is_none() && self.identifier().is_some()
+    }
+
+    pub fn identifier(&self) -> Option<&Ident> {
+        fn identifier(pattern: &Pat) -> Option<&Ident> {
+            match pattern {
+                Pat::Ident(PatIdent { ident, subpat: None, .. }) => Some(ident),
+                Pat::Reference(reference) => identifier(&reference.pat),
+                _ => None
+            }
+        }
+
+        identifier(&self.pattern)
+    }
+
+    pub fn identifiers(&self) -> Vec<String> {
+        fn collect(pattern: &Pat, identifiers: &mut Vec<String>) {
+            match pattern {
+                Pat::Box(boxed) => collect(&boxed.pat, identifiers),
+                Pat::Ident(ident) => {
+                    identifiers.push(ident.ident.to_string());
+
+                    if let Some((_, subpat)) = &ident.subpat {
+                        collect(subpat, identifiers);
+                    }
+                }
+                Pat::Or(or) => or.cases.iter().for_each(|case| collect(case, identifiers)),
+                Pat::Reference(reference) => collect(&reference.pat, identifiers),
+                Pat::Slice(slice) => slice.elems.iter().for_each(|elem| collect(elem, identifiers)),
+                Pat::Struct(structure) =>
+                    structure.fields.iter().for_each(|field| collect(&field.pat, identifiers)),
+                Pat::Tuple(tuple) => tuple.elems.iter().for_each(|elem| collect(elem, identifiers)),
+                Pat::TupleStruct(tuple) =>
+                    tuple.pat.elems.iter().for_each(|elem| collect(elem, identifiers)),
+                Pat::Type(typed) => collect(&typed.pat, identifiers),
+                _ => {}
+            }
+        }
+
+        let mut identifiers = Vec::new();
+
+        collect(&self.pattern, &mut identifiers);
+
+        identifiers
+    }
+}
+
+#[cfg(feature = "result")]
+pub enum MapErr {
+    Context(Message),
+    Map(Expr),
+}
+
+#[cfg(all(feature = "result", feature = "trace"))]
+impl Display for MapErr {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Context(message) => write!(fmt, "{{ context: {message} }}"),
+            Self::Map(expr) => write!(fmt, "{{ map_err: \"{}\" }}", expr.to_token_stream())
+        }
+    }
+}
+
+#[cfg(feature = "result")]
+pub struct Retry {
+    pub attempts: Expr,
+    pub backoff: Option<Expr>,
+}
+
+#[cfg(all(feature = "result", feature = "trace"))]
+impl Display for Retry {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let backoff = display(&self.backoff.as_ref().map(ToTokens::to_token_stream));
+
+        write!(fmt, "{{ attempts: \"{}\", backoff: {backoff} }}", self.attempts.to_token_stream())
     }
 }
 
+#[derive(Clone)]
 pub struct OnExpr {
     pub captured: Option<Capture>,
     pub expr: Expr,
@@ -99,11 +191,36 @@ impl Display for OnFail {
     }
 }
 
+#[cfg(feature = "result")]
+pub struct OnFailArm {
+    pub failed: OnFail,
+    pub pattern: Option<Capture>,
+}
+
+#[cfg(all(feature = "result", feature = "trace"))]
+impl Display for OnFailArm {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let pattern = display(&self.pattern);
+
+        write!(fmt, "{{ failed: {}, pattern: {pattern} }}", self.failed)
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
 pub enum OnSuccess {
     Expr(OnExpr),
     Message(Message),
 }
 
+impl OnSuccess {
+    pub const fn captured(&self) -> Option<&Capture> {
+        match self {
+            Self::Expr(expr) => expr.captured.as_ref(),
+            Self::Message(message) => message.captured.as_ref(),
+        }
+    }
+}
+
 #[cfg(feature = "trace")]
 impl Display for OnSuccess {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
@@ -114,17 +231,67 @@ impl Display for OnSuccess {
     }
 }
 
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum Escape {
+    Break(Option<Lifetime>),
+    Continue(Option<Lifetime>),
+    Exit(Expr),
+    Panic(Message),
+    Return(Option<OnExpr>),
+}
+
+#[cfg(feature = "result")]
+impl Escape {
+    pub const fn captured(&self) -> bool {
+        matches!(
+            self,
+            Self::Panic(Message { captured: Some(_), .. }) |
+                Self::Return(Some(OnExpr { captured: Some(_), .. }))
+        )
+    }
+}
+
+#[cfg(feature = "trace")]
+impl Display for Escape {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let (escape, value) = match self {
+            Self::Break(label) => ("break", label.as_ref().map(ToTokens::to_token_stream)),
+            Self::Continue(label) => ("continue", label.as_ref().map(ToTokens::to_token_stream)),
+            Self::Exit(code) => ("exit", Some(code.to_token_stream())),
+            Self::Panic(message) => ("panic", Some(message.build_message())),
+            Self::Return(value) =>
+                ("return", value.as_ref().map(|value| value.expr.to_token_stream())),
+        };
+        let value = display(&value);
+
+        write!(fmt, "{{ {escape}: {value} }}")
+    }
+}
+
+#[derive(Clone)]
 pub struct WhenExpr {
     pub expr: Expr,
     pub tried: bool,
     pub ok_when: bool,
+    pub escape: Option<Escape>,
+    pub binding: Option<Capture>,
+}
+
+impl WhenExpr {
+    pub const fn diverges(&self) -> bool {
+        self.tried || self.escape.is_some()
+    }
 }
 
 #[cfg(feature = "trace")]
 impl Display for WhenExpr {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let escape = display(&self.escape);
+        let binding = display(&self.binding);
+
         write!(
-            fmt, "{{ expr: \"{}\", ok_when: {}, tried: {} }}",
+            fmt, "{{ expr: \"{}\", ok_when: {}, tried: {}, escape: {escape}, binding: {binding} }}",
             self.expr.to_token_stream(), self.ok_when, self.tried
         )
     }
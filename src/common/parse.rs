@@ -1,42 +1,66 @@
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenTree};
 use quote::ToTokens;
-use syn::{Error, Expr, Lit};
+use syn::{Error, Expr, Lifetime, LitStr, Pat, PatOr, PatType, Type};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Peek};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Paren;
 
-use crate::common::{Message, OnFail, OnSuccess, WhenExpr};
+use crate::common::{Escape, Message, OnFail, OnSuccess, WhenExpr};
+#[cfg(feature = "result")]
+use crate::common::{MapErr, OnFailArm, Retry};
 #[cfg(any(feature = "result", feature = "option"))]
 use crate::common::models::Capture;
 use crate::common::models::OnExpr;
 use crate::common::parse::utils::search_for_ident;
 
 mod kw {
+    #[cfg(feature = "result")]
+    custom_keyword![backoff];
     custom_keyword![debug];
+    custom_keyword![finally];
+    custom_keyword![map];
+    custom_keyword![warn];
     custom_keyword![when];
 }
 
-const DEBUG_SECTION: &str = "debug";
+pub const BREAK_SECTION: &str = "break";
+pub const CONTINUE_SECTION: &str = "continue";
+pub const DEBUG_SECTION: &str = "debug";
+pub const EXIT_SECTION: &str = "exit";
+pub const FINALLY_SECTION: &str = "finally";
+pub const MAP_SECTION: &str = "map";
+pub const PANIC_SECTION: &str = "panic";
+pub const RETURN_SECTION: &str = "return";
+pub const WARN_SECTION: &str = "warn";
+pub const WHEN_SECTION: &str = "when";
 
 impl Parse for Capture {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let reference = input.peek(Token![&]);
+        let pattern = <Pat>::parse(input)?;
 
-        if reference { <Token![&]>::parse(input)?; }
+        if !input.peek(Token![|]) {
+            return Ok(Self { guard: None, pattern });
+        }
+
+        let mut cases = Punctuated::new();
 
-        let mutable = input.peek(Token!(mut));
+        cases.push_value(pattern);
 
-        if mutable { <Token![mut]>::parse(input)?; }
+        while input.peek(Token![|]) {
+            cases.push_punct(<Token![|]>::parse(input)?);
+            cases.push_value(<Pat>::parse(input)?);
+        }
 
         Ok(Self {
-            identifier: <Ident>::parse(input)?.to_string(),
-            mutable,
-            reference,
+            guard: None,
+            pattern: Pat::Or(PatOr { attrs: Vec::new(), leading_vert: None, cases }),
         })
     }
 }
 
-pub fn decode_expr_type(expr: &Expr) -> &'static str {
+pub const fn decode_expr_type(expr: &Expr) -> &'static str {
     match expr {
         Expr::Array(_) => "array",
         Expr::Assign(_) => "assign",
@@ -78,18 +102,95 @@ pub fn decode_expr_type(expr: &Expr) -> &'static str {
         Expr::Verbatim(_) => "verbatim",
         Expr::While(_) => "while",
         Expr::Yield(_) => "yield",
-        _ => unimplemented!()
+        _ => "unknown"
+    }
+}
+
+#[cfg(feature = "result")]
+pub fn capture_failed(failed: &mut OnFail, capture: &Capture) {
+    if let Some(message) = &mut failed.message {
+        capture_message(message, capture);
+    }
+
+    if let Some(expr) = &mut failed.expr {
+        capture_expr(expr, capture);
+    }
+}
+
+#[cfg(feature = "result")]
+pub fn capture_escape(escape: &mut Escape, capture: &Capture) {
+    match escape {
+        Escape::Panic(message) => capture_message(message, capture),
+        Escape::Return(Some(expr)) => capture_expr(expr, capture),
+        _ => {}
+    }
+}
+
+#[cfg(feature = "result")]
+fn capture_expr(expr: &mut OnExpr, capture: &Capture) {
+    let identifiers = capture.identifiers();
+
+    if identifiers.iter().any(|ident| search_for_ident(expr.expr.to_token_stream(), ident)) {
+        expr.captured = Some(capture.clone());
+    }
+}
+
+pub fn capture_message(message: &mut Message, capture: &Capture) {
+    let named = message.args.iter()
+        .flatten()
+        .filter_map(|expr| match expr {
+            Expr::Assign(named) => Some(named.left.to_token_stream().to_string()),
+            _ => None
+        })
+        .collect::<Vec<_>>();
+
+    let identifiers = capture.identifiers();
+
+    let referenced = message.args.iter()
+        .flatten()
+        .any(|expr| identifiers.iter().any(|ident| search_for_ident(
+            match expr {
+                Expr::Assign(named) => named.right.to_token_stream(),
+                _ => expr.to_token_stream()
+            },
+            ident,
+        ))) ||
+        utils::format_identifiers(&message.fmt.value())
+            .iter()
+            .any(|ident| identifiers.contains(ident) && !named.contains(ident));
+
+    if referenced {
+        message.captured = Some(capture.clone());
     }
 }
 
 pub fn parse_debug(
-    input: ParseStream, capture: &Option<Capture>,
+    input: ParseStream, declared: Option<&mut Option<Capture>>,
 ) -> syn::Result<Option<Message>> {
-    if input.peek(Token![@]) && input.peek2(kw::debug) {
+    parse_output(input, kw::debug, declared)
+}
+
+pub fn parse_warn(
+    input: ParseStream, declared: Option<&mut Option<Capture>>,
+) -> syn::Result<Option<Message>> {
+    parse_output(input, kw::warn, declared)
+}
+
+fn parse_output<T: Peek>(
+    input: ParseStream, token: T, declared: Option<&mut Option<Capture>>,
+) -> syn::Result<Option<Message>> {
+    if input.peek(Token![@]) && input.peek2(token) {
         <Token![@]>::parse(input)?;
-        <kw::debug>::parse(input)?;
 
-        let message = parse_message(input, DEBUG_SECTION, capture)?;
+        let section = <Ident>::parse(input)?;
+
+        if let Some(declared) = declared {
+            parse_declared_capture(input, declared)?;
+        }
+
+        check_section_body(input, &section, "a message string literal", "\"...\"")?;
+
+        let message = parse_message(input, &section, None)?;
 
         parse_optional_semicolon(input)?;
 
@@ -99,111 +200,285 @@ pub fn parse_debug(
     }
 }
 
-pub fn parse_failed<T: Peek>(
-    input: ParseStream, token: T, section: &str, capture: Option<Capture>,
-) -> syn::Result<Option<OnFail>> {
+#[cfg(feature = "result")]
+pub fn parse_context<T: Peek>(input: ParseStream, token: T) -> syn::Result<Option<MapErr>> {
     if input.peek(Token![@]) && input.peek2(token) {
         <Token![@]>::parse(input)?;
-        <Ident>::parse(input)?;
 
-        let message = parse_message(input, section, &capture).ok();
+        let section = <Ident>::parse(input)?;
 
-        let expr = if !input.is_empty() && !input.peek(Token![@]) {
-            if message.is_some() {
-                <Token![;]>::parse(input)?;
-            }
+        check_section_body(input, &section, "a message string literal", "\"...\"")?;
 
-            if input.is_empty() {
-                None
-            } else {
-                let expr = parse_expression(input, section)?;
-                let captured = capture.and_then(
-                    |capture| if utils::search_for_ident(expr.to_token_stream(), &capture.identifier) {
-                        Some(capture)
-                    } else {
-                        None
-                    }
-                );
+        let message = parse_message(input, &section, None)?;
 
-                Some(OnExpr { captured, expr })
-            }
-        } else {
-            parse_optional_semicolon(input)?;
+        parse_optional_semicolon(input)?;
 
+        Ok(Some(MapErr::Context(message)))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(feature = "result")]
+pub fn parse_map_err<T: Peek>(input: ParseStream, token: T) -> syn::Result<Option<MapErr>> {
+    if input.peek(Token![@]) && input.peek2(token) {
+        <Token![@]>::parse(input)?;
+
+        let section = <Ident>::parse(input)?;
+
+        check_section_body(input, &section, "an expression", "|err| ...")?;
+
+        Ok(Some(MapErr::Map(parse_expression(input, &section)?)))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(feature = "result")]
+pub fn parse_retry<T: Peek>(input: ParseStream, token: T) -> syn::Result<Option<Retry>> {
+    if input.peek(Token![@]) && input.peek2(token) {
+        <Token![@]>::parse(input)?;
+
+        let section = <Ident>::parse(input)?;
+
+        check_section_body(input, &section, "an attempt count", "3, backoff = duration")?;
+
+        let attempts = <Expr>::parse(input)?;
+        let backoff = if input.peek(Token![,]) {
+            <Token![,]>::parse(input)?;
+            <kw::backoff>::parse(input)?;
+            <Token![=]>::parse(input)?;
+
+            Some(<Expr>::parse(input)?)
+        } else {
             None
         };
 
-        Ok(Some(OnFail { expr, message }))
+        parse_optional_semicolon(input)?;
+
+        Ok(Some(Retry { attempts, backoff }))
+    } else {
+        Ok(None)
+    }
+}
+
+pub fn parse_finally(input: ParseStream) -> syn::Result<Option<Expr>> {
+    if input.peek(Token![@]) && input.peek2(kw::finally) {
+        <Token![@]>::parse(input)?;
+
+        let section = <Ident>::parse(input)?;
+
+        check_section_body(input, &section, "an expression", "{ ... }")?;
+
+        Ok(Some(parse_expression(input, &section)?))
+    } else {
+        Ok(None)
+    }
+}
+
+pub fn parse_map(input: ParseStream) -> syn::Result<Option<(Ident, Expr)>> {
+    if input.peek(Token![@]) && input.peek2(kw::map) {
+        <Token![@]>::parse(input)?;
+
+        let section = <Ident>::parse(input)?;
+
+        check_section_body(input, &section, "a closure or function", "|v| v.to_string()")?;
+
+        let map = parse_expression(input, &section)?;
+
+        Ok(Some((section, map)))
+    } else {
+        Ok(None)
+    }
+}
+
+pub fn check_guarded<'a>(
+    success: impl IntoIterator<Item = &'a OnSuccess>, otherwise: Option<&OnSuccess>,
+    section: &str,
+) -> syn::Result<()> {
+    let guard = success.into_iter()
+        .find_map(|success| success.captured().and_then(|captured| captured.guard.as_ref()));
+
+    match (guard, otherwise) {
+        (Some(guard), None) => Err(Error::new(
+            guard.span(), format!("a guarded `@{section}` requires an `@otherwise` section"),
+        )),
+        _ => Ok(())
+    }
+}
+
+pub fn check_map(
+    when: &WhenExpr, map: Option<(Ident, Expr)>, success: &str,
+) -> syn::Result<Option<Expr>> {
+    match map {
+        Some((section, _)) if !when.ok_when => Err(Error::new(
+            section.span(),
+            format!(
+                "`@{section}` requires an expression mode `@{success}` section, e.g. `@{success} foo()`"
+            ),
+        )),
+        map => Ok(map.map(|(_, map)| map)),
+    }
+}
+
+#[cfg(feature = "option")]
+pub fn parse_failed<T: Peek>(input: ParseStream, token: T) -> syn::Result<Option<OnFail>> {
+    if input.peek(Token![@]) && input.peek2(token) {
+        <Token![@]>::parse(input)?;
+
+        let section = <Ident>::parse(input)?;
+
+        Ok(Some(parse_failed_body(input, &section)?))
     } else {
         Ok(None)
     }
 }
 
+#[cfg(feature = "result")]
+pub fn parse_failed_arm<T: Peek>(input: ParseStream, token: T) -> syn::Result<Option<OnFailArm>> {
+    if input.peek(Token![@]) && input.peek2(token) {
+        <Token![@]>::parse(input)?;
+
+        let section = <Ident>::parse(input)?;
+        let pattern = if peek_failed_arm(input) { Some(parse_arm_capture(input)?) } else { None };
+
+        Ok(Some(OnFailArm { failed: parse_failed_body(input, &section)?, pattern }))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_failed_body(input: ParseStream, section: &Ident) -> syn::Result<OnFail> {
+    check_section_body(input, section, "a message and/or an expression", "\"...\"")?;
+
+    let message = if input.peek(LitStr) {
+        Some(parse_message(input, section, None)?)
+    } else {
+        None
+    };
+
+    let expr = if !input.is_empty() && !input.peek(Token![@]) {
+        if message.is_some() {
+            <Token![;]>::parse(input)?;
+        }
+
+        if input.is_empty() || input.peek(Token![@]) {
+            None
+        } else {
+            let expr = parse_expression(input, section)?;
+
+            Some(OnExpr { captured: None, expr })
+        }
+    } else {
+        parse_optional_semicolon(input)?;
+
+        None
+    };
+
+    Ok(OnFail { expr, message })
+}
+
 pub fn parse_message(
-    input: ParseStream, section: &str, capture: &Option<Capture>,
+    input: ParseStream, section: &Ident, capture: Option<&Capture>,
 ) -> syn::Result<Message> {
-    if let Some(literal) = input.cursor().literal() {
-        if literal.0.to_string().starts_with('\"') {
-            let literal = <Lit>::parse(input)?;
+    let fmt = input.parse::<LitStr>().map_err(
+        |err| Error::new(err.span(), format!("{section} expects a message string literal"))
+    )?;
 
-            match literal {
-                Lit::Str(_) => {}
-                _ => return Err(Error::new(literal.span(), format!("{section} expects a string literal")))
-            }
+    let mut exprs = Vec::new();
 
-            let mut exprs = Vec::new();
-            let mut captured = &None;
+    while input.peek(Token![,]) {
+        <Token![,]>::parse(input)?;
 
-            while input.peek(Token![,]) {
-                <Token![,]>::parse(input)?;
+        if input.is_empty() || input.peek(Token![;]) || input.peek(Token![@]) {
+            break;
+        }
 
-                let expr = <Expr>::parse(input)?;
+        exprs.push(<Expr>::parse(input)?);
+    }
 
-                if let Some(checked) = capture {
-                    if search_for_ident(expr.to_token_stream(), &checked.identifier) {
-                        captured = capture;
-                    }
-                }
+    let mut message = Message {
+        args: if exprs.is_empty() { None } else { Some(exprs) },
+        captured: None,
+        fmt,
+    };
 
-                match expr {
-                    Expr::Array(_) | Expr::Await(_) |
-                    Expr::Binary(_) | Expr::Block(_) |
-                    Expr::Call(_) | Expr::Cast(_) |
-                    Expr::Field(_) | Expr::Group(_) |
-                    Expr::If(_) | Expr::Index(_) |
-                    Expr::Lit(_) | Expr::Macro(_) |
-                    Expr::Match(_) | Expr::MethodCall(_) |
-                    Expr::Paren(_) | Expr::Path(_) |
-                    Expr::Range(_) | Expr::Reference(_) |
-                    Expr::Repeat(_) | Expr::Try(_) |
-                    Expr::TryBlock(_) | Expr::Tuple(_) |
-                    Expr::Unary(_) | Expr::Unsafe(_) => {}
-                    _ => return Err(Error::new(
-                        expr.span(),
-                        format!("{:?} is not a supported {section} expression", decode_expr_type(&expr)),
-                    ))
-                }
+    if let Some(capture) = capture {
+        capture_message(&mut message, capture);
+    }
 
-                exprs.push(expr);
-            }
+    Ok(message)
+}
 
-            return Ok(Message {
-                args: if exprs.is_empty() { None } else { Some(exprs) },
-                captured: captured.clone(),
-                fmt: literal,
-            });
-        }
+pub fn check_binding(when: Option<&WhenExpr>, section: &Ident, success: &str) -> syn::Result<()> {
+    if section == success && when.is_some_and(|when| when.binding.is_some()) {
+        return Err(Error::new(
+            section.span(),
+            format!("`@{section}` can not be combined with a `let` binding, use `@map`"),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn check_duplicate(parsed: &mut Vec<String>, section: &Ident) -> syn::Result<()> {
+    let name = section.to_string();
+
+    if parsed.contains(&name) {
+        return Err(Error::new(section.span(), format!("duplicate `@{name}` section")));
+    }
+
+    parsed.push(name);
+
+    Ok(())
+}
+
+#[cfg(feature = "result")]
+pub fn check_repeated(
+    parsed: &mut Vec<String>, section: &Ident, input: ParseStream,
+) -> syn::Result<()> {
+    let fork = input.fork();
+    let arm = fork.parse::<Token![@]>().is_ok() && fork.call(Ident::parse_any).is_ok() &&
+        peek_failed_arm(&fork);
+
+    if arm { Ok(()) } else { check_duplicate(parsed, section) }
+}
+
+pub fn parse_section(input: ParseStream, sections: &[&str]) -> syn::Result<Ident> {
+    let expected = || sections.iter()
+        .map(|section| format!("`@{section}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fork = input.fork();
+
+    if fork.parse::<Token![@]>().is_err() {
+        let span = fork.parse::<TokenTree>().map_or_else(|_| input.span(), |token| token.span());
+
+        return Err(Error::new(span, format!("expected a section, one of {}", expected())));
+    }
+
+    let section = fork.call(Ident::parse_any)?;
+    let name = section.to_string();
+
+    if sections.contains(&name.as_str()) {
+        return Ok(section);
     }
 
-    Err(Error::new(Span::call_site(), "No Message"))
+    Err(Error::new(
+        section.span(),
+        utils::closest_section(&name, sections).map_or_else(
+            || format!("unknown section `@{name}`, expected one of {}", expected()),
+            |closest| format!("unknown section `@{name}`, did you mean `@{closest}`?"),
+        ),
+    ))
 }
 
 pub fn parse_successful<T: Peek>(
-    input: ParseStream, when: &WhenExpr, capture_id: &str, section: &str, token: T,
+    input: ParseStream, ok_when: bool, capture_id: &str, token: T,
 ) -> syn::Result<Option<OnSuccess>> {
     let capture = Some(Capture::from(capture_id));
 
-    Ok(if when.ok_when {
+    Ok(if ok_when {
         let success = Ident::new(capture_id, Span::call_site());
 
         Some(OnSuccess::Expr(OnExpr {
@@ -211,85 +486,202 @@ pub fn parse_successful<T: Peek>(
             expr: parse_quote! { #success },
         }))
     } else {
-        parse_expression_success(input, token, section, capture)?
+        parse_expression_success(input, token, capture)?
     })
 }
 
-pub fn parse_when<T: Peek>(input: ParseStream, success_kw: T) -> syn::Result<WhenExpr> {
+pub fn peek_when(input: ParseStream, success: &str) -> bool {
+    let fork = input.fork();
+
+    if fork.parse::<Token![@]>().is_err() {
+        return true;
+    }
+
+    match fork.call(Ident::parse_any) {
+        Ok(section) if section == WHEN_SECTION => true,
+        Ok(section) if section == success => {
+            let mut previous_at = false;
+
+            while let Ok(token) = fork.parse::<TokenTree>() {
+                match token {
+                    TokenTree::Ident(ident) if previous_at && ident == WHEN_SECTION => return false,
+                    TokenTree::Punct(punct) => previous_at = punct.as_char() == '@',
+                    _ => previous_at = false
+                }
+            }
+
+            true
+        }
+        _ => false
+    }
+}
+
+pub fn parse_when<T: Peek>(
+    input: ParseStream, success_kw: T, success: &str,
+) -> syn::Result<WhenExpr> {
+    let binding = parse_binding(input)?;
+
     if input.peek(Token![@]) {
         <Token![@]>::parse(input)?;
     }
-    let ok_when: bool = if input.peek(kw::when) {
-        <kw::when>::parse(input)?;
 
+    let ok_when = if input.peek(kw::when) {
         false
     } else if input.peek(success_kw) {
-        <Ident>::parse(input)?;
-
         true
     } else {
-        return Err(Error::new(
-            Span::call_site(), "expected @when or @ok expression",
-        ));
+        return Err(Error::new(input.span(), "expected a `@when` section, e.g. `@when foo()`"));
     };
 
-    let expr = <Expr>::parse(input)?;
+    let section = <Ident>::parse(input)?;
 
-    match expr {
-        Expr::Await(_) | Expr::Call(_) |
-        Expr::Cast(_) | Expr::Field(_) |
-        Expr::Group(_) | Expr::If(_) |
-        Expr::Index(_) | Expr::Macro(_) |
-        Expr::Match(_) | Expr::MethodCall(_) |
-        Expr::Path(_) | Expr::Reference(_) => {
-            parse_optional_semicolon(input)?;
+    check_section_body(input, &section, "an expression", "foo()")?;
 
-            Ok(WhenExpr { expr, tried: false, ok_when })
-        }
-        Expr::Try(try_expr) => {
-            let tried = true;
-            let expr = try_expr.expr.as_ref().clone();
+    let expr = <Expr>::parse(input)?;
 
+    let (expr, tried) = match expr {
+        Expr::Try(try_expr) => {
             parse_optional_semicolon(input)?;
 
-            Ok(WhenExpr { expr, tried, ok_when })
+            (utils::invoke_try_block(*try_expr.expr, success), true)
         }
-        Expr::Block(_) if utils::block_contains_try(&expr) =>
-            Err(Error::new(expr.span(), "block can not contain a try expression")),
         Expr::Block(_) =>
-            Ok(WhenExpr { expr, tried: false, ok_when }),
-        _ => Err(Error::new(
+            (expr, false),
+        Expr::TryBlock(_) =>
+            (utils::invoke_try_block(expr, success), false),
+        Expr::Lit(_) => return Err(Error::new(
             expr.span(),
             format!("{:?} is not a supported when expression", decode_expr_type(&expr)),
-        ))
+        )),
+        _ => {
+            parse_optional_semicolon(input)?;
+
+            (expr, false)
+        }
+    };
+
+    Ok(WhenExpr { expr, tried, ok_when: ok_when || binding.is_some(), escape: None, binding })
+}
+
+fn parse_binding(input: ParseStream) -> syn::Result<Option<Capture>> {
+    if !input.peek(Token![let]) {
+        return Ok(None);
+    }
+
+    <Token![let]>::parse(input)?;
+
+    let mut binding = <Capture>::parse(input)?;
+
+    if input.peek(Token![:]) {
+        binding.pattern = Pat::Type(PatType {
+            attrs: Vec::new(),
+            pat: Box::new(binding.pattern),
+            colon_token: <Token![:]>::parse(input)?,
+            ty: Box::new(<Type>::parse(input)?),
+        });
+    }
+
+    <Token![=]>::parse(input)?;
+
+    Ok(Some(binding))
+}
+
+pub fn parse_escape(
+    input: ParseStream, escape: Option<&(Ident, Escape)>,
+) -> syn::Result<Option<(Ident, Escape)>> {
+    <Token![@]>::parse(input)?;
+
+    let section = input.call(Ident::parse_any)?;
+
+    if let Some((escaped, _)) = escape {
+        return Err(Error::new(
+            section.span(), format!("`@{section}` can not be combined with `@{escaped}`"),
+        ));
+    }
+
+    let escape = match section.to_string().as_str() {
+        BREAK_SECTION => Escape::Break(parse_label(input)?),
+        CONTINUE_SECTION => Escape::Continue(parse_label(input)?),
+        EXIT_SECTION => {
+            check_section_body(input, &section, "an exit code", "2")?;
+
+            Escape::Exit(match <Expr>::parse(input)? {
+                Expr::Paren(paren) => *paren.expr,
+                code => code,
+            })
+        }
+        PANIC_SECTION => {
+            check_section_body(input, &section, "a message string literal", "\"...\"")?;
+
+            Escape::Panic(parse_message(input, &section, None)?)
+        }
+        _ if input.is_empty() || input.peek(Token![@]) || input.peek(Token![;]) =>
+            Escape::Return(None),
+        _ => Escape::Return(Some(OnExpr {
+            captured: None,
+            expr: match <Expr>::parse(input)? {
+                Expr::Paren(paren) => *paren.expr,
+                value => value,
+            },
+        })),
+    };
+
+    parse_optional_semicolon(input)?;
+
+    Ok(Some((section, escape)))
+}
+
+fn parse_label(input: ParseStream) -> syn::Result<Option<Lifetime>> {
+    if input.peek(Lifetime) { Ok(Some(<Lifetime>::parse(input)?)) } else { Ok(None) }
+}
+
+pub fn attach_escape(mut when: WhenExpr, escape: Option<(Ident, Escape)>) -> syn::Result<WhenExpr> {
+    match escape {
+        Some((section, _)) if when.tried => Err(Error::new(
+            section.span(),
+            format!("`@{section}` can not be combined with a tried `@when` expression"),
+        )),
+        None if !when.tried && when.binding.is_some() => Err(Error::new(
+            when.binding.as_ref().unwrap().pattern.span(),
+            "a `let` binding requires a diverging section, e.g. `@return`, or a tried `@when` \
+            expression",
+        )),
+        escape => {
+            when.escape = escape.map(|(_, escape)| escape);
+
+            Ok(when)
+        }
     }
 }
 
 fn parse_expression_success<T: Peek>(
-    input: ParseStream, token: T, section: &str, capture: Option<Capture>,
+    input: ParseStream, token: T, capture: Option<Capture>,
 ) -> syn::Result<Option<OnSuccess>> {
     if input.peek(Token![@]) && input.peek2(token) {
         <Token![@]>::parse(input)?;
-        <Ident>::parse(input)?;
 
+        let section = <Ident>::parse(input)?;
         let mut captured = capture;
+        let declared = input.peek(Paren);
 
-        if input.peek(Paren) {
-            let content;
+        if declared {
+            captured = Some(parse_arm_capture(input)?);
+        }
 
-            parenthesized!(content in input);
+        check_section_body(input, &section, "a message or an expression", "\"...\"")?;
 
-            captured = Some(content.parse::<Capture>()?);
+        Ok(Some(
+            if input.peek(LitStr) {
+                let mut message = parse_message(input, &section, captured.as_ref())?;
 
-            <Token![=>]>::parse(input)?;
-        }
+                if declared {
+                    message.captured = captured;
+                }
 
-        Ok(Some(
-            if let Ok(message) = parse_message(input, section, &captured) {
                 parse_optional_semicolon(input)?;
                 OnSuccess::Message(message)
             } else {
-                let expr = parse_expression(input, section)?;
+                let expr = parse_expression(input, &section)?;
 
                 OnSuccess::Expr(OnExpr { captured, expr })
             }
@@ -299,7 +691,77 @@ fn parse_expression_success<T: Peek>(
     }
 }
 
-fn parse_expression(input: ParseStream, section: &str) -> syn::Result<Expr> {
+fn parse_arm_capture(input: ParseStream) -> syn::Result<Capture> {
+    let content;
+
+    parenthesized!(content in input);
+
+    let mut capture = content.parse::<Capture>()?;
+
+    if input.peek(Token![if]) {
+        <Token![if]>::parse(input)?;
+
+        capture.guard = Some(<Expr>::parse(input)?);
+    }
+
+    <Token![=>]>::parse(input)?;
+
+    Ok(capture)
+}
+
+fn parse_declared_capture(input: ParseStream, declared: &mut Option<Capture>) -> syn::Result<()> {
+    if !peek_declared_capture(input) {
+        return Ok(());
+    }
+
+    let content;
+
+    parenthesized!(content in input);
+
+    let capture = content.parse::<Capture>()?;
+
+    <Token![=>]>::parse(input)?;
+
+    declare_capture(declared, capture)
+}
+
+pub fn declare_capture(declared: &mut Option<Capture>, capture: Capture) -> syn::Result<()> {
+    if capture.identifier().is_none() {
+        return Err(Error::new(capture.pattern.span(), "error capture expects an identifier"));
+    }
+
+    match declared {
+        Some(existing) if *existing != capture => Err(Error::new(
+            capture.pattern.span(),
+            format!(
+                "conflicting capture {:?}, the error is already captured as {:?}",
+                capture.pattern.to_token_stream().to_string(),
+                existing.pattern.to_token_stream().to_string()
+            ),
+        )),
+        _ => {
+            *declared = Some(capture);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "result")]
+fn peek_failed_arm(input: ParseStream) -> bool {
+    let fork = input.fork();
+
+    input.peek(Paren) && fork.parse::<TokenTree>().is_ok() &&
+        (fork.peek(Token![=>]) || fork.peek(Token![if]))
+}
+
+fn peek_declared_capture(input: ParseStream) -> bool {
+    let fork = input.fork();
+
+    input.peek(Paren) && fork.parse::<TokenTree>().is_ok() && fork.peek(Token![=>])
+}
+
+fn parse_expression(input: ParseStream, section: &Ident) -> syn::Result<Expr> {
     let expr = <Expr>::parse(input)?;
 
     match expr {
@@ -318,6 +780,19 @@ fn parse_expression(input: ParseStream, section: &str) -> syn::Result<Expr> {
     Ok(expr)
 }
 
+fn check_section_body(
+    input: ParseStream, section: &Ident, expects: &str, example: &str,
+) -> syn::Result<()> {
+    if input.is_empty() || input.peek(Token![@]) {
+        Err(Error::new(
+            section.span(),
+            format!("`@{section}` expects {expects}, e.g. `@{section} {example}`"),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn parse_optional_semicolon(input: ParseStream) -> syn::Result<()> {
     if let Some(punct) = input.cursor().punct() {
         if punct.0.as_char() == ';' {
@@ -331,46 +806,174 @@ fn parse_optional_semicolon(input: ParseStream) -> syn::Result<()> {
 pub mod utils {
     #[cfg(any(feature = "option", feature = "result"))]
     use proc_macro2::{TokenStream, TokenTree};
-    use syn::{Expr, Stmt};
-
-    pub(super) fn block_contains_try(expr: &Expr) -> bool {
-        if let Expr::Block(block_expr) = expr {
-            if block_expr.block.stmts
-                .iter()
-                .any(
-                    |stmt| {
-                        match stmt {
-                            Stmt::Expr(expr) |
-                            Stmt::Semi(expr, _) =>
-                                if let Expr::Try(_) = expr {
-                                    return true;
-                                },
-                            _ => {}
-                        }
-                        false
-                    }
-                ) {
-                return true;
+    use proc_macro2::{Ident, Span};
+    use syn::{Expr, ExprBlock, Item, Lifetime, Lit, Stmt};
+    use syn::visit_mut::{self, VisitMut};
+
+    pub(super) fn invoke_try_block(expr: Expr, success: &str) -> Expr {
+        match expr {
+            Expr::TryBlock(try_block) => {
+                let mut block = try_block.block;
+                let mut try_operator = TryOperator {
+                    label: Lifetime::new("'try_block", Span::mixed_site()),
+                    success,
+                    tried: false,
+                };
+
+                try_operator.visit_block_mut(&mut block);
+
+                match block.stmts.last_mut() {
+                    Some(Stmt::Expr(tail)) => *tail = try_operator.wrap(&quote! { #tail }),
+                    _ => block.stmts.push(Stmt::Expr(try_operator.wrap(&quote! { () }))),
+                }
+
+                if try_operator.tried {
+                    let label = try_operator.label;
+
+                    Expr::Verbatim(quote! { #label: #block })
+                } else {
+                    Expr::Block(ExprBlock { attrs: Vec::new(), label: None, block })
+                }
             }
+            expr => expr
         }
+    }
 
-        false
+    // re-routes the `?` operators of a try block to the end of the block, leaving nested
+    // closures, async blocks and items alone since their `?` belongs to them
+    struct TryOperator<'a> {
+        label: Lifetime,
+        success: &'a str,
+        tried: bool,
     }
 
-    #[cfg(any(feature = "option", feature = "result"))]
-    pub fn search_for_ident(stream: TokenStream, checked: &str) -> bool {
-        for each in stream {
-            match each {
-                TokenTree::Group(group) => {
-                    return search_for_ident(group.stream(), checked);
-                }
-                TokenTree::Ident(ident) if ident == checked => {
-                    return true;
+    impl TryOperator<'_> {
+        fn wrap(&self, value: &TokenStream) -> Expr {
+            if self.success == "some" {
+                parse_quote! { Some(#value) }
+            } else {
+                parse_quote! { Ok(#value) }
+            }
+        }
+    }
+
+    impl VisitMut for TryOperator<'_> {
+        fn visit_expr_mut(&mut self, expr: &mut Expr) {
+            match expr {
+                Expr::Async(_) | Expr::Closure(_) | Expr::TryBlock(_) => {}
+                Expr::Try(try_expr) => {
+                    self.visit_expr_mut(&mut try_expr.expr);
+
+                    let label = &self.label;
+                    let tried = &try_expr.expr;
+                    let value = Ident::new("value", Span::mixed_site());
+
+                    *expr = if self.success == "some" {
+                        parse_quote! {
+                            match #tried { Some(#value) => #value, None => break #label None }
+                        }
+                    } else {
+                        let error = Ident::new("error", Span::mixed_site());
+
+                        parse_quote! {
+                            match #tried {
+                                Ok(#value) => #value,
+                                Err(#error) => break #label Err(#error),
+                            }
+                        }
+                    };
+
+                    self.tried = true;
                 }
-                _ => {}
+                _ => visit_mut::visit_expr_mut(self, expr),
             }
         }
 
-        false
+        fn visit_item_mut(&mut self, _item: &mut Item) {}
+    }
+
+    pub(super) fn format_identifiers(fmt: &str) -> Vec<String> {
+        let mut identifiers = Vec::new();
+        let mut chars = fmt.chars().peekable();
+
+        while let Some(chr) = chars.next() {
+            if chr != '{' {
+                continue;
+            }
+
+            if chars.peek() == Some(&'{') {
+                chars.next();
+
+                continue;
+            }
+
+            let placeholder = chars.by_ref().take_while(|chr| *chr != '}').collect::<String>();
+            let (argument, spec) = placeholder.split_once(':').unwrap_or((&placeholder, ""));
+
+            identifiers.extend(format_identifier(argument.trim()));
+
+            for parameter in spec.split_inclusive('$').filter(|part| part.ends_with('$')) {
+                let parameter = parameter.trim_end_matches('$');
+                let start = parameter
+                    .rfind(|chr: char| !(chr.is_alphanumeric() || chr == '_'))
+                    .map_or(0, |idx| idx + 1);
+
+                identifiers.extend(format_identifier(&parameter[start..]));
+            }
+        }
+
+        identifiers
+    }
+
+    pub(super) fn closest_section<'a>(name: &str, sections: &[&'a str]) -> Option<&'a str> {
+        sections.iter()
+            .map(|section| (edit_distance(name, section), *section))
+            .filter(|(distance, section)| *distance <= 2 || section.starts_with(name))
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, section)| section)
+    }
+
+    fn edit_distance(source: &str, target: &str) -> usize {
+        let target = target.chars().collect::<Vec<_>>();
+        let mut distances = (0..=target.len()).collect::<Vec<_>>();
+
+        for (row, source_chr) in source.chars().enumerate() {
+            let mut diagonal = distances[0];
+
+            distances[0] = row + 1;
+
+            for (col, target_chr) in target.iter().enumerate() {
+                let substitution = diagonal + usize::from(source_chr != *target_chr);
+
+                diagonal = distances[col + 1];
+                distances[col + 1] = substitution.min(distances[col] + 1).min(diagonal + 1);
+            }
+        }
+
+        distances[target.len()]
+    }
+
+    fn format_identifier(argument: &str) -> Option<String> {
+        let argument = argument.trim_start_matches("r#");
+
+        argument
+            .starts_with(|chr: char| chr.is_alphabetic() || chr == '_')
+            .then(|| argument.to_string())
+    }
+
+    #[cfg(any(feature = "option", feature = "result"))]
+    pub fn search_for_ident(stream: TokenStream, checked: &str) -> bool {
+        stream.into_iter().any(|each| match each {
+            TokenTree::Group(group) => search_for_ident(group.stream(), checked),
+            TokenTree::Ident(ident) => ident == checked,
+            // inline format args, e.g. `eprintln!("{err}")`, only show up inside the literal
+            TokenTree::Literal(literal) => match Lit::new(literal) {
+                Lit::Str(fmt) => {
+                    format_identifiers(&fmt.value()).iter().any(|ident| ident == checked)
+                }
+                _ => false
+            },
+            TokenTree::Punct(_) => false
+        })
     }
 }
@@ -1,21 +1,172 @@
 use proc_macro2::{Ident, Span, TokenStream};
+use syn::{Expr, LitStr};
 
-use crate::common::Capture;
+use crate::common::{Capture, Escape, Message, WhenExpr};
 
-pub fn build_captured(captured: &Option<Capture>) -> TokenStream {
+pub fn build_captured(captured: Option<&Capture>) -> TokenStream {
     captured
-        .as_ref()
         .map_or_else(
             || quote! { _ },
             |captured| {
-                let captured_ident = Ident::new(&captured.identifier, Span::call_site());
-
-                match (captured.reference, captured.mutable) {
-                    (false, false) => quote! { #captured_ident },
-                    (true, false) => quote! { &#captured_ident },
-                    (true, true) => quote! { &mut #captured_ident },
-                    (false, true) => quote! { mut #captured_ident },
-                }
+                let pattern = &captured.pattern;
+
+                quote! { #pattern }
             },
         )
 }
+
+pub fn build_guard(captured: Option<&Capture>) -> TokenStream {
+    captured
+        .and_then(|captured| captured.guard.as_ref())
+        .map_or_else(TokenStream::new, |guard| quote! { if #guard })
+}
+
+pub fn build_finally(finally: Option<&Expr>) -> TokenStream {
+    finally.map_or_else(TokenStream::new, |finally| quote! { #finally; })
+}
+
+pub fn build_escape(escape: &Escape, finally: &TokenStream) -> TokenStream {
+    let escape = match escape {
+        Escape::Break(label) => quote! { break #label },
+        Escape::Continue(label) => quote! { continue #label },
+        Escape::Exit(code) => quote! {
+            {
+                use ::std::io::Write as _;
+
+                let _ = ::std::io::stdout().flush();
+                let _ = ::std::io::stderr().flush();
+
+                ::std::process::exit(#code)
+            }
+        },
+        Escape::Panic(message) => {
+            let message_fmt = message.build_message();
+
+            quote! { panic!(#message_fmt) }
+        }
+        Escape::Return(value) => {
+            let value = value.as_ref().map(|value| &value.expr);
+
+            quote! { return #value }
+        }
+    };
+
+    quote! { #finally #escape; }
+}
+
+pub fn build_scrutinee(when: &WhenExpr) -> &Expr {
+    let mut when_expr = &when.expr;
+
+    // a struct literal is not allowed bare in scrutinee position, so its parentheses must stay
+    while let Expr::Paren(paren) = when_expr {
+        if contains_exterior_struct(&paren.expr) {
+            break;
+        }
+
+        when_expr = &paren.expr;
+    }
+
+    when_expr
+}
+
+pub fn build_mapped_when(when: &WhenExpr, map: &Expr) -> WhenExpr {
+    let mut when_expr = &when.expr;
+
+    while let Expr::Paren(paren) = when_expr {
+        when_expr = &paren.expr;
+    }
+
+    WhenExpr { expr: Expr::Verbatim(quote! { (#when_expr).map(#map) }), ..when.clone() }
+}
+
+fn contains_exterior_struct(expr: &Expr) -> bool {
+    match expr {
+        Expr::Struct(_) => true,
+        Expr::Assign(expr) => {
+            contains_exterior_struct(&expr.left) || contains_exterior_struct(&expr.right)
+        }
+        Expr::AssignOp(expr) => {
+            contains_exterior_struct(&expr.left) || contains_exterior_struct(&expr.right)
+        }
+        Expr::Binary(expr) => {
+            contains_exterior_struct(&expr.left) || contains_exterior_struct(&expr.right)
+        }
+        Expr::Range(expr) => {
+            expr.from.as_deref().is_some_and(contains_exterior_struct)
+                || expr.to.as_deref().is_some_and(contains_exterior_struct)
+        }
+        Expr::Await(expr) => contains_exterior_struct(&expr.base),
+        Expr::Call(expr) => contains_exterior_struct(&expr.func),
+        Expr::Cast(expr) => contains_exterior_struct(&expr.expr),
+        Expr::Field(expr) => contains_exterior_struct(&expr.base),
+        Expr::Index(expr) => contains_exterior_struct(&expr.expr),
+        Expr::MethodCall(expr) => contains_exterior_struct(&expr.receiver),
+        Expr::Reference(expr) => contains_exterior_struct(&expr.expr),
+        Expr::Try(expr) => contains_exterior_struct(&expr.expr),
+        Expr::Type(expr) => contains_exterior_struct(&expr.expr),
+        Expr::Unary(expr) => contains_exterior_struct(&expr.expr),
+        _ => false,
+    }
+}
+
+pub fn wrap_finally(when: &WhenExpr, expansion: TokenStream, finally: &TokenStream) -> TokenStream {
+    if finally.is_empty() {
+        expansion
+    } else if when.ok_when {
+        let value = Ident::new("finally_value", Span::mixed_site());
+
+        quote! { { let #value = #expansion; #finally #value } }
+    } else {
+        quote! { { #expansion #finally } }
+    }
+}
+
+pub fn wrap_binding(when: &WhenExpr, expansion: TokenStream) -> TokenStream {
+    let Some(binding) = &when.binding else { return expansion };
+    let pattern = &binding.pattern;
+
+    quote! { let #pattern = #expansion; }
+}
+
+pub fn build_debug_message(message: &Message, feature: &str) -> TokenStream {
+    let message_fmt = message.build_message();
+    let discarded = build_discarded(message, &quote! { all(debug_assertions, feature = #feature) });
+
+    quote! {
+        #[allow(unexpected_cfgs)]
+        {
+            #[cfg(all(debug_assertions, feature = #feature))]
+            println!(#message_fmt);
+            #discarded
+        }
+    }
+}
+
+pub fn build_warn_message(message: &Message, feature: &str) -> TokenStream {
+    let message = Message {
+        args: message.args.clone(),
+        captured: message.captured.clone(),
+        fmt: LitStr::new(&format!("warning: {}", message.fmt.value()), message.fmt.span()),
+    };
+    let message_fmt = message.build_message();
+
+    let discarded = build_discarded(&message, &quote! { feature = #feature });
+
+    quote! {
+        #[allow(unexpected_cfgs)]
+        {
+            #[cfg(feature = #feature)]
+            eprintln!(#message_fmt);
+            #discarded
+        }
+    }
+}
+
+fn build_discarded(message: &Message, predicate: &TokenStream) -> TokenStream {
+    message.captured.as_ref()
+        .and_then(Capture::identifier)
+        .map_or_else(TokenStream::new, |ident| quote! {
+            #[cfg(not(#predicate))]
+            let _ = &#ident;
+        })
+}
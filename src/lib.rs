@@ -6,6 +6,8 @@
 // ==============================================================
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::items_after_statements)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::doc_overindented_list_items)]
 // ==============================================================
 #![doc(html_root_url = "https://docs.rs/macrofied-toolbox/0.4.3")]
 
@@ -104,9 +106,6 @@
 extern crate bitflags;
 #[cfg(any(feature = "result", feature = "option"))]
 #[macro_use]
-extern crate cfg_if;
-#[cfg(any(feature = "result", feature = "option"))]
-#[macro_use]
 extern crate quote;
 #[cfg(any(feature = "result", feature = "option"))]
 #[macro_use]
@@ -136,14 +135,26 @@ mod tests;
 ///
 /// ## Anotomy of the `option!` macro
 ///
-/// The `option!` macro consists of a `@when` section and one to three optional evaluation 
-/// sections `@some`, `@debug` and/or `@none`, at least one must be defined.
+/// The `option!` macro consists of a `@when` section and one to four optional evaluation
+/// sections `@some`, `@debug`, `@warn` and/or `@none`, at least one must be defined, and
+/// optional `@map`, `@continue`, `@break`, `@exit`, `@panic` or `@return`, and `@finally`
+/// sections.
+///
+/// Sections can be defined in any order, each one only once.
 ///
 /// When the `option!` macro is used in place of an expression and the intention is to
 /// assign the `Some(T)` value, the `@when` section can be skipped and replaced with a
 /// simplified `@some` section, which behaves as the `@when` section, _* see below for
 /// more details_
 ///
+/// When the intention is to bind the `Some(T)` value for the rest of the enclosing scope, the
+/// `@when` section can be prefixed with `let <pattern>[: <type>] =`, the `option!` macro then
+/// expands to a `let` statement, which requires a tried `@when` expression or a `@continue`,
+/// `@break`, `@exit`, `@panic` or `@return` section, so that the `@none` path diverges, the
+/// `Some(T)` value is bound as is, so a `@some` section can not be defined, use `@map` instead
+///
+/// __`Example:`__ `option! { let path = @when args.get(1) @return false }`<br/>
+///
 /// <br/>\* _code block_ `<expr>`_s, can not be terminated with a_
 /// `;`_, i.e._ `{ ... }`~~`;`~~<br/>
 ///
@@ -157,27 +168,42 @@ mod tests;
 ///           `@debug` and/or `@none`
 /// * `[;]` - _optional_, section terminator
 ///
+/// A `?` inside a code block `<expr>` returns from the enclosing function as usual, prefix
+/// the block with `try` to evaluate its `None` with the `@some`, `@debug` and `@none`
+/// sections instead, i.e. `@when try { let foo = foo()?; bar(foo)? }`, just like a `try`
+/// block its final expression is wrapped in `Some`, while `return` and `.await` keep their
+/// meaning, a `?` inside a nested closure or macro call is not routed
+///
+/// Any expression that evaluates to an `Option<T>` is supported, wrap it in parentheses if it
+/// is ambiguous, i.e. `@when (foo()?)` is evaluated as is instead of being tried
+///
 /// __`Example A:`__ `@when foo();`<br/>
 /// __`Example B:`__ `@when foo()?;`<br/>
 ///
 /// ### `@some`
 ///
-/// The `@some` section is defined as `@some <[[(identifier) =>]<message|expr>[;]|[<expr>[?][;]]]`
+/// The `@some` section is defined as `@some <[[(pattern) [if <guard>] =>]<message|expr>[;]|[<expr>[?][;]]]`
 ///
 /// * `@some` - required section identifier
 /// * __In Success Mode__
-///     * `[(identifier) =>]` - _optional_, custom defined identifier which maps to
-///                             the `Some(T)` value
+///     * `[(pattern) =>]` - _optional_, custom defined identifier or irrefutable pattern
+///                          which destructures the `Some(T)` value
 ///     * `<message|expr>`
 ///         * `message` - outputs to `stdout` with a `println!` statement, therefore has
 ///                       the same `args`
 ///         * `expr` - any expression to evaluate<br/><br/>
-/// _* can access_ `Some(T)` _value with the_ `some` _keyword or custom identifier_<br/><br/>
+/// _* can access_ `Some(T)` _value with the_ `some` _keyword or custom bindings_<br/><br/>
 ///     * `[;]` - _optional_, section terminator<br/><br/>
 /// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::Some`<br/><br/>
 /// __`Example A:`__ `@some "success: {}", some;`<br/>
 /// __`Example B:`__ `@some (foo) => "success: {}", foo;`<br/>
 /// __`Example C:`__ `@some (foo) => { success(foo); }`<br/>
+/// __`Example D:`__ `@some ((name, size)) => "success: {} - {}", name, size;`<br/>
+/// * __With A Guard__
+///     * `[if <guard>]` - _optional, a condition on the custom bindings, if it fails the
+///                        value is handled by the_ `@otherwise` _section, which is
+///                        required with a guard_<br/><br/>
+/// __`Example:`__ `@some (n) if n > 0 => "positive: {}", n;`<br/>
 /// * __In Expression Mode__
 ///     * `<expr>` - an expression that must evaluate to an `Option<T>` value
 ///     * `[?]` - _optional_, try operator, returns `None` after completing
@@ -185,11 +211,43 @@ mod tests;
 ///     * `[;]` - _optional_, section terminator<br/><br/>
 /// __`Example A:`__ `@some foo();`<br/>
 /// __`Example B:`__ `@some foo()?;`<br/>
+///
+/// ### `@otherwise`
+///
+/// The `@otherwise` section is defined as `@otherwise [(pattern) =>]<message|expr>[;]`
+///
+/// \* _only evaluates if the_ `@some` _section's guard fails_
+///
+/// * `@otherwise` - required section identifier, must follow the `@some` section
+/// * `[(pattern) =>]` - _optional_, custom defined identifier or pattern which
+///                      destructures the `Some(T)` value
+/// * `<message|expr>` - same as the `@some` section<br/><br/>
+/// _* can access_ `Some(T)` _value with the_ `some` _keyword or custom bindings_<br/><br/>
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@otherwise "not positive: {}", some;`
+///
+/// ### `@map`
+///
+/// The `@map` section is defined as `@map <expr>[;]`
+///
+/// \* _requires the expression mode_ `@some` _section or a_ `let` _binding, transforms the_
+/// `Some(T)` _value before it is returned or bound, the_ `@none` _section's value must then have
+/// the mapped type_
+///
+/// * `@map` - required section identifier
+/// * `<expr>` - a closure or function that takes the `Some(T)` value
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@map |v| v.trim().to_owned()`<br/>
+///
 /// ### `@debug`
 ///
 /// The `@debug` section is defined as `@debug <message>[;]`
 ///
-/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`<br/>
+/// \* _only outputs if the calling crate enables its own_ `option-debug` _feature and is
+/// built with_ `debug_assertions`
 ///
 /// * `@debug` - required section identifier
 /// * `message` - outputs to `stdout` with a `println!` statement, therefore has the same `args`
@@ -197,6 +255,21 @@ mod tests;
 ///
 /// __`Example:`__ `@debug "dbg: foo failed!";`
 ///
+/// ### `@warn`
+///
+/// The `@warn` section is defined as `@warn <message>[;]`
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`<br/>
+/// \* _only outputs if the calling crate enables its own_ `option-warn` _feature, in both
+/// debug and release builds_
+///
+/// * `@warn` - required section identifier
+/// * `message` - outputs to `stderr` with a `eprintln!` statement prefixed with `warning: `,
+///               therefore has the same `args`
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@warn "foo is missing, using defaults";`
+///
 /// ### `@none`
 ///
 /// The `@none` section is defined as `@none [<message>[;]][<expr>][;]`, must
@@ -215,6 +288,80 @@ mod tests;
 /// __`Example B:`__ `@none "err: foo failed!"`<br/>
 /// __`Example C:`__ `@none "err: foo failed!"; { on_fail_baz(); }`<br/>
 ///
+/// ### `@continue` / `@break`
+///
+/// The `@continue` and `@break` sections are defined as `@continue ['label][;]` and
+/// `@break ['label][;]`, only one of them, `@exit`, `@panic` or `@return` can be defined
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@none` _sections, it can not be combined with the_ `[?]` _try
+/// operator_
+///
+/// * `@continue` / `@break` - required section identifier, continues or breaks out of the
+///                            enclosing loop
+/// * `['label]` - _optional_, label of the loop to continue or break out of
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@continue 'lines`<br/>
+///
+/// ### `@exit`
+///
+/// The `@exit` section is defined as `@exit <code>[;]`, it can not be combined with the
+/// `@continue`, `@break`, `@panic` or `@return` sections
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@none` _sections, it flushes_ `stdout` _and_ `stderr` _and then
+/// ends the process, it can not be combined with the_ `[?]` _try operator_
+///
+/// * `@exit` - required section identifier
+/// * `<code>` - an `i32` exit code expression
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@exit 2`<br/>
+///
+/// ### `@panic`
+///
+/// The `@panic` section is defined as `@panic <message>[;]`, it can not be combined with the
+/// `@continue`, `@break`, `@exit` or `@return` sections
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@none` _sections, the panic location is the macro invocation,
+/// it can not be combined with the_ `[?]` _try operator_
+///
+/// * `@panic` - required section identifier
+/// * `message` - panics with a `panic!` statement, therefore has the same `args`
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@panic "missing {}", key`<br/>
+///
+/// ### `@return`
+///
+/// The `@return` section is defined as `@return [<expr>][;]`, it can not be combined with the
+/// `@continue`, `@break`, `@exit` or `@panic` sections
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Option::None`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@none` _sections, it returns from the enclosing function,
+/// which can have any return type, it can not be combined with the_ `[?]` _try operator_
+///
+/// * `@return` - required section identifier
+/// * `[<expr>]` - _optional_, the value to return, omit it in functions returning `()`
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@return ExitCode::FAILURE`<br/>
+///
+/// ### `@finally`
+///
+/// The `@finally` section is defined as `@finally <expr>[;]`
+///
+/// \* _always evaluates, after the_ `@some` _or_ `@none` _sections, including before
+/// returning_ `None` _with the_ `[?]` _try operator_
+///
+/// * `@finally` - required section identifier
+/// * `<expr>` - any expression to evaluate
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@finally { spinner.stop(); }`<br/>
+///
 /// ## Example
 ///
 /// * Success Mode
@@ -285,14 +432,26 @@ pub fn option(input: TokenStream) -> TokenStream {
 ///
 /// ## Anotomy of the `result!` macro
 ///
-/// The `result!` macro consists of a required `@when` section and one to three
-/// optional evaluation sections `@ok`, `@debug` and/or `@error`, at least one must be
-/// defined.
+/// The `result!` macro consists of a required `@when` section and one to four
+/// optional evaluation sections `@ok`, `@debug`, `@warn` and/or `@error`, at least one
+/// must be defined, and optional `@map`, `@retry`, `@map_err` or `@context`, `@continue`,
+/// `@break`, `@exit`, `@panic` or `@return`, and `@finally` sections.
+///
+/// Sections can be defined in any order, each one only once, except for the `@ok` and
+/// `@error` arms.
 ///
 /// When the `result!` macro is used in place of an expression and the intention is to
 /// assign the `Ok(T)` value, the `@when` section can be skipped and replaced with an
 /// `@ok` section, which behaves as the `@when` section, _* see below for more details_
 ///
+/// When the intention is to bind the `Ok(T)` value for the rest of the enclosing scope, the
+/// `@when` section can be prefixed with `let <pattern>[: <type>] =`, the `result!` macro then
+/// expands to a `let` statement, which requires a tried `@when` expression or a `@continue`,
+/// `@break`, `@exit`, `@panic` or `@return` section, so that the `@error` path diverges, the
+/// `Ok(T)` value is bound as is, so an `@ok` section can not be defined, use `@map` instead
+///
+/// __`Example:`__ `result! { let file = @when File::open(path) @return false }`<br/>
+///
 /// <br/>\* _code block_ `<expr>`_s, can not be terminated with a_
 /// `;`_, i.e._ `{ ... }`~~`;`~~<br/>
 ///
@@ -302,32 +461,55 @@ pub fn option(input: TokenStream) -> TokenStream {
 ///
 /// * `@when` - _optional_, section identifier
 /// * `<expr>` - an expression that must evaluate to a `Result<T,E>` value
-/// * `[?]` - _optional_, try operator will, returns `Result::Err` after completing
-///           `@debug` and/or `@error`
+/// * `[?]` - _optional_, try operator, returns `Result::Err` after completing
+///           `@debug` and/or `@error`, the error is converted with `From::from`
+///           just like the `?` operator, `@debug` and `@error` borrow the error,
+///           i.e. `err` is a `&E`, so it can still be returned
 /// * `[;]` - _optional_, section terminator
 ///
+/// A `?` inside a code block `<expr>` returns from the enclosing function as usual, prefix
+/// the block with `try` to evaluate its `Result::Err` with the `@ok`, `@debug` and `@error`
+/// sections instead, i.e. `@when try { let foo = foo()?; bar(foo)? }`, just like a `try`
+/// block its final expression is wrapped in `Ok`, while `return` and `.await` keep their
+/// meaning, unlike a `try` block errors are not converted, so every `?` must share the same
+/// error type, a `?` inside a nested closure or macro call is not routed
+///
+/// Any expression that evaluates to a `Result<T,E>` is supported, wrap it in parentheses if it
+/// is ambiguous, i.e. `@when (foo()?)` is evaluated as is instead of being tried
+///
 /// __`Example A:`__ `@when foo()?;`<br/>
 /// __`Example B:`__ `@when foo()?;`<br/>
 ///
 /// ### `@ok`
 ///
-/// The `@ok` section is defined as `@ok [[(identifier) =>]<message|expr>[;]|[<expr>[?][;]]`
+/// The `@ok` section is defined as `@ok [[(pattern) [if <guard>] =>]<message|expr>[;]|[<expr>[?][;]]`
 ///
 /// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Ok`
 ///
 /// * `@ok` - required section identifier
 /// * __In Success Mode__
-///     * `[(identifier) =>]` - _optional_, custom defined identifier which maps to
-///                             the `Ok(T)` value
+///     * `[(pattern) =>]` - _optional_, custom defined identifier or irrefutable pattern
+///                          which destructures the `Ok(T)` value
 ///     * `<message|expr>` -
 ///         * `message` - outputs to `stdout` with a `println!` statement, therefore has
 ///                       the same `args`
 ///         * `expr` - any expression to evaluate<br/><br/>
-/// _* can access_ `Ok(T)` _value with the_ `ok` _keyword or custom identifier_<br/><br/>
+/// _* can access_ `Ok(T)` _value with the_ `ok` _keyword or custom bindings_<br/><br/>
 ///     * `[;]` - _optional_, section terminator<br/><br/>
 /// __`Example:`__ `@ok "success: {}", ok;`<br/>
 /// __`Example:`__ `@ok (foo) => "success: {}", foo;`<br/>
 /// __`Example:`__ `@ok (foo) => { success(foo) }`<br/>
+/// __`Example:`__ `@ok (Config { port, .. }) => "listening on: {}", port;`<br/>
+/// * __In Success Mode, With Multiple Arms__
+///     * `@ok` _can be repeated, each section becomes an arm of a single_ `match` _on the_
+///       `Ok(T)` _value, patterns may be refutable and are checked for exhaustiveness, only
+///       one_ `@ok` _section can be without a pattern_<br/><br/>
+/// __`Example:`__ `@ok (Some(foo)) => "found: {}", foo; @ok (None) => "not found";`<br/>
+/// * __With A Guard__
+///     * `[if <guard>]` - _optional, a condition on the custom bindings, if it fails the
+///                        value is handled by the_ `@otherwise` _section, which is
+///                        required with a guard_<br/><br/>
+/// __`Example:`__ `@ok (n) if n > 0 => "positive: {}", n;`<br/>
 /// * __In Expression Mode__
 ///     * `<expr>` - an expression that must evaluate to an `Option<T>` value
 ///     * `[?]` - _optional_, try operator, returns `None` after completing
@@ -336,39 +518,231 @@ pub fn option(input: TokenStream) -> TokenStream {
 /// __`Example A:`__ `@ok foo();`<br/>
 /// __`Example B:`__ `@ok foo()?;`<br/>
 ///
+/// ### `@otherwise`
+///
+/// The `@otherwise` section is defined as `@otherwise [(pattern) =>]<message|expr>[;]`
+///
+/// \* _only evaluates if no_ `@ok` _section matched the_ `Ok(T)` _value, i.e. a guard failed_
+///
+/// * `@otherwise` - required section identifier, must follow the `@ok` sections
+/// * `[(pattern) =>]` - _optional_, custom defined identifier or pattern which
+///                      destructures the `Ok(T)` value
+/// * `<message|expr>` - same as the `@ok` section<br/><br/>
+/// _* can access_ `Ok(T)` _value with the_ `ok` _keyword or custom bindings_<br/><br/>
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@otherwise "not positive: {}", ok;`
+///
+/// ### `@map`
+///
+/// The `@map` section is defined as `@map <expr>[;]`
+///
+/// \* _requires the expression mode_ `@ok` _section or a_ `let` _binding, transforms the_
+/// `Ok(T)` _value before it is returned or bound, the_ `@error` _section's value must then have
+/// the mapped type_
+///
+/// * `@map` - required section identifier
+/// * `<expr>` - a closure or function that takes the `Ok(T)` value
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@map |v| v.trim().to_owned()`<br/>
+///
 /// ### `@debug`
 ///
-/// The `@debug` section is defined as `@debug <message>[;]`
+/// The `@debug` section is defined as `@debug [(identifier) =>]<message>[;]`
 ///
-/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`<br/>
+/// \* _only outputs if the calling crate enables its own_ `result-debug` _feature and is
+/// built with_ `debug_assertions`
 ///
 /// * `@debug` - required section identifier
+/// * `[(identifier) =>]` - _optional_, custom defined identifier which maps to
+///                         the `Err(E)` value, accepts `&` and `mut`
 /// * `message` - outputs to `stdout` with a `println!` statement, therefore has
 ///               the same `args`<br/><br/>
-/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword_<br/><br/>
+/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword or custom identifier_<br/><br/>
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example A:`__ `@debug "dbg: foo failed! - {}", err;`<br/>
+/// __`Example B:`__ `@debug (e) => "dbg: foo failed! - {}", e;`
+///
+/// ### `@warn`
+///
+/// The `@warn` section is defined as `@warn [(identifier) =>]<message>[;]`
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`<br/>
+/// \* _only outputs if the calling crate enables its own_ `result-warn` _feature, in both
+/// debug and release builds_
+///
+/// * `@warn` - required section identifier
+/// * `[(identifier) =>]` - _optional_, custom defined identifier which maps to
+///                         the `Err(E)` value, same as the `@debug` section
+/// * `message` - outputs to `stderr` with a `eprintln!` statement prefixed with `warning: `,
+///               therefore has the same `args`<br/><br/>
+/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword or custom identifier_<br/><br/>
 /// * `[;]` - _optional_, section terminator
 ///
-/// __`Example:`__ `@debug "dbg: foo failed! - {}", err;`
+/// __`Example:`__ `@warn "foo failed, retrying later - {}", err;`
 ///
 /// ### `@none`
 ///
-/// The `@error` section is defined as `@error [<message>[;]][<expr>][;]`, must
-/// provide at least a `message` and/or `expr`
+/// The `@error` section is defined as `@error [(identifier) =>][<message>[;]][<expr>][;]`,
+/// must provide at least a `message` and/or `expr`
 ///
-/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`<br/>
+/// \* _a custom identifier is shared with_ `@debug`_, defining a different one in each
+/// section does not compile_
 ///
 /// * `@error` - required section identifier
+/// * `[(identifier) =>]` - _optional_, custom defined identifier which maps to
+///                         the `Err(E)` value, accepts `&` and `mut`
 /// * `[message][;]` - _optional_, outputs to `stderr` with a `eprintln!` statement, therefore
 ///                    has the same `args`<br/><br/>
 /// \* _requires the_ `;` _terminator if an_ `<expr>[;]` _is also defined_<br/>
-/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword_<br/><br/>
+/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword or custom identifier_<br/><br/>
 /// * `[<expr>]` - _optional_, any expression to evaluate<br/><br/>
-/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword_<br/><br/>
+/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword or custom identifier_<br/><br/>
 /// * `[;]` - _optional_, section terminator
 ///
 /// __`Example A:`__ `@err "err: foo failed! - {}", err`<br/>
 /// __`Example B:`__ `{ on_fail_baz(err); }`<br/>
 /// __`Example C:`__ `@err "err: foo failed! - {}", err; { on_fail_baz(err); }`<br/>
+/// __`Example D:`__ `@err (e) => "err: foo failed! - {}", e; { on_fail_baz(e); }`<br/>
+/// * __With Multiple Arms__
+///     * `@error` _can be repeated as_ `@error (pattern) [if <guard>] => ...`_, each section
+///       becomes an arm of a single_ `match` _on the_ `Err(E)` _value, after the shared_
+///       `@debug` _output, an_ `@error` _without a pattern matches any error_
+///     * _the arms must be exhaustive, a single_ `@error` _without a pattern can be used as
+///       the last arm to match any remaining error_
+///     * _with the_ `[?]` _try operator the arms match a borrow of the error, which is
+///       then returned_<br/><br/>
+/// __`Example:`__ `@error (FooError::NotFound(path)) => "missing {}", path; @error "err: {}", err`<br/>
+/// __`Example:`__ `@error (e) if e.kind() == ErrorKind::NotFound => { create() }`<br/>
+///
+/// ### `@retry`
+///
+/// The `@retry` section is defined as `@retry <attempts>[, backoff = <expr>][;]`
+///
+/// \* _re-evaluates the_ `@when` _expression while it is_ `Result::Err`_, up to the total
+/// number of attempts, only the final failure evaluates the_ `@warn` _and_ `@error`
+/// _sections or is returned with the_ `[?]` _try operator_
+///
+/// * `@retry` - required section identifier
+/// * `<attempts>` - total number of attempts, a `usize` expression
+/// * `[, backoff = <expr>]` - _optional_, a `Duration` to sleep before each retry<br/><br/>
+/// \* _can access the current 1-based attempt with the_ `attempt` _keyword_<br/><br/>
+/// * `[;]` - _optional_, section terminator
+///
+/// \* `@debug` _outputs for every failed attempt and can also access_ `attempt`
+///
+/// __`Example:`__ `@retry 3, backoff = Duration::from_millis(100 * attempt as u64)`<br/>
+///
+/// ### `@map_err`
+///
+/// The `@map_err` section is defined as `@map_err <expr>[;]`
+///
+/// \* _requires the_ `[?]` _try operator, transforms the_ `Err(E)` _value after_ `@debug`_,_
+/// `@warn` _and_ `@error` _evaluate, and before it is returned_
+///
+/// * `@map_err` - required section identifier
+/// * `<expr>` - a closure or function that takes the `Err(E)` value, the result is converted
+///              with `From::from`
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@map_err |err| MyError::Io(path.clone(), err)`<br/>
+///
+/// ### `@context`
+///
+/// The `@context` section is defined as `@context <message>[;]`, it can not be combined with
+/// the `@map_err` section
+///
+/// \* _requires the_ `[?]` _try operator, replaces the_ `Err(E)` _value with a_ `String`
+/// _formatted as_ `"<message>: <err>"`_, which is then converted with_ `From::from`, therefore
+/// `E` _must implement_ `Display` _and the returned error type must implement_ `From<String>`_,
+/// i.e._ `String` _or_ `Box<dyn Error>`_, the original error is only kept as text, so its_
+/// `source()` _is lost, use the_ `@map_err` _section to keep it_
+///
+/// * `@context` - required section identifier
+/// * `message` - formatted with a `format_args!` statement, therefore has the same `args`
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@context "reading {}", path`<br/>
+///
+/// ### `@continue` / `@break`
+///
+/// The `@continue` and `@break` sections are defined as `@continue ['label][;]` and
+/// `@break ['label][;]`, only one of them, `@exit`, `@panic` or `@return` can be defined
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@error` _sections, it can not be combined with the_ `[?]` _try
+/// operator_
+///
+/// * `@continue` / `@break` - required section identifier, continues or breaks out of the
+///                            enclosing loop
+/// * `['label]` - _optional_, label of the loop to continue or break out of
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@continue 'lines`<br/>
+///
+/// ### `@exit`
+///
+/// The `@exit` section is defined as `@exit <code>[;]`, it can not be combined with the
+/// `@continue`, `@break`, `@panic` or `@return` sections
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@error` _sections, it flushes_ `stdout` _and_ `stderr` _and then
+/// ends the process, it can not be combined with the_ `[?]` _try operator_
+///
+/// * `@exit` - required section identifier
+/// * `<code>` - an `i32` exit code expression
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@exit 2`<br/>
+///
+/// ### `@panic`
+///
+/// The `@panic` section is defined as `@panic <message>[;]`, it can not be combined with the
+/// `@continue`, `@break`, `@exit` or `@return` sections
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@error` _sections, the panic location is the macro invocation,
+/// it can not be combined with the_ `[?]` _try operator_
+///
+/// * `@panic` - required section identifier
+/// * `message` - panics with a `panic!` statement, therefore has the same `args`<br/><br/>
+/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword or custom identifier_<br/><br/>
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@panic "could not open {}: {}", path, err`<br/>
+///
+/// ### `@return`
+///
+/// The `@return` section is defined as `@return [<expr>][;]`, it can not be combined with the
+/// `@continue`, `@break`, `@exit` or `@panic` sections
+///
+/// \* _only evaluates if the result of the_ `@when` _expression is_ `Result::Err`_, after the_
+/// `@debug`_,_ `@warn` _and_ `@error` _sections, it returns from the enclosing function,
+/// which can have any return type, it can not be combined with the_ `[?]` _try operator_
+///
+/// * `@return` - required section identifier
+/// * `[<expr>]` - _optional_, the value to return, omit it in functions returning `()`<br/><br/>
+/// \* _can access_ `Result::Err(err)` _with_ `err` _keyword or custom identifier_<br/><br/>
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@return ExitCode::FAILURE`<br/>
+///
+/// ### `@finally`
+///
+/// The `@finally` section is defined as `@finally <expr>[;]`
+///
+/// \* _always evaluates, after the_ `@ok` _or_ `@error` _sections, including before
+/// returning_ `Result::Err` _with the_ `[?]` _try operator_
+///
+/// * `@finally` - required section identifier
+/// * `<expr>` - any expression to evaluate
+/// * `[;]` - _optional_, section terminator
+///
+/// __`Example:`__ `@finally { spinner.stop(); }`<br/>
 ///
 /// ## Example
 ///
@@ -1,6 +1,10 @@
 #[cfg(feature = "trace")]
 use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "trace")]
+use quote::ToTokens;
+use syn::Expr;
+
 use crate::common::{Message, OnFail, OnSuccess, WhenExpr};
 #[cfg(feature = "trace")]
 use crate::display;
@@ -13,19 +17,28 @@ mod tokenize;
 pub struct OptionMacro {
     when: WhenExpr,
     some: Option<OnSuccess>,
+    otherwise: Option<OnSuccess>,
+    map: Option<Expr>,
     debug: Option<Message>,
+    warn: Option<Message>,
     none: Option<OnFail>,
+    finally: Option<Expr>,
 }
 
 #[cfg(feature = "trace")]
 impl Display for OptionMacro {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         let some = display(&self.some);
+        let otherwise = display(&self.otherwise);
+        let map = display(&self.map.as_ref().map(ToTokens::to_token_stream));
         let debug = display(&self.debug);
+        let warn = display(&self.warn);
         let none = display(&self.none);
+        let finally = display(&self.finally.as_ref().map(ToTokens::to_token_stream));
 
         write!(
-            fmt, "option! {{\n  when: {},\n  some: {some},\n  debug: {debug},\n  none: {none}\n}}",
+            fmt,
+            "option! {{\n  when: {},\n  some: {some},\n  otherwise: {otherwise},\n  map: {map},\n  debug: {debug},\n  warn: {warn},\n  none: {none},\n  finally: {finally}\n}}",
             self.when
         )
     }
@@ -35,6 +48,7 @@ impl OptionMacro {
     fn definition(&self) -> Parts {
         (if self.some.is_some() { Parts::SOME } else { Parts::NOTHING }) |
             (if self.debug.is_some() { Parts::DEBUG } else { Parts::NOTHING }) |
+            (if self.warn.is_some() { Parts::WARN } else { Parts::NOTHING }) |
             (if self.none.is_some() { Parts::NONE } else { Parts::NOTHING })
     }
 }
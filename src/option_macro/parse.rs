@@ -1,17 +1,31 @@
+use proc_macro2::Span;
+use syn::Error;
 use syn::parse::{Parse, ParseStream};
 
-use crate::common::parse::{parse_debug, parse_failed, parse_successful, parse_when};
+use crate::common::parse::{
+    attach_escape, BREAK_SECTION, check_binding, check_duplicate, check_guarded, check_map,
+    CONTINUE_SECTION, DEBUG_SECTION, EXIT_SECTION, FINALLY_SECTION, MAP_SECTION, PANIC_SECTION,
+    parse_debug, parse_escape, parse_failed, parse_finally, parse_map, parse_section,
+    parse_successful, parse_warn, parse_when, peek_when, RETURN_SECTION, WARN_SECTION, WHEN_SECTION,
+};
 use crate::common::tracing::{trace_parsed, trace_source};
 use crate::option_macro::OptionMacro;
 
 mod kw {
     custom_keyword![none];
+    custom_keyword![otherwise];
     custom_keyword![some];
 }
 
 const NONE_SECTION: &str = "none";
+const OTHERWISE_SECTION: &str = "otherwise";
 const SOME_IDENT: &str = "some";
 const SOME_SECTION: &str = "some";
+const SECTIONS: &[&str] = &[
+    WHEN_SECTION, SOME_SECTION, OTHERWISE_SECTION, MAP_SECTION, DEBUG_SECTION, WARN_SECTION,
+    NONE_SECTION, CONTINUE_SECTION, BREAK_SECTION, EXIT_SECTION, PANIC_SECTION, RETURN_SECTION,
+    FINALLY_SECTION,
+];
 
 impl Parse for OptionMacro {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -19,14 +33,83 @@ impl Parse for OptionMacro {
 
         #[inline]
         fn parse(input: ParseStream) -> syn::Result<OptionMacro> {
-            let when = parse_when(input, kw::some)?;
-
-            Ok(OptionMacro {
-                some: parse_successful(input, &when, SOME_IDENT, SOME_SECTION, kw::some)?,
-                when,
-                debug: parse_debug(input, &None)?,
-                none: parse_failed(input, kw::none, NONE_SECTION, None)?,
-            })
+            let mut parsed = Vec::new();
+            let mut first_section = None;
+            let mut when = None;
+            let mut some = None;
+            let mut otherwise = None;
+            let mut map = None;
+            let mut debug = None;
+            let mut warn = None;
+            let mut none = None;
+            let mut escape = None;
+            let mut finally = None;
+
+            if peek_when(input, SOME_SECTION) {
+                let when_expr = parse_when(input, kw::some, SOME_SECTION)?;
+
+                if when_expr.ok_when {
+                    parsed.push(String::from(SOME_SECTION));
+                    some = parse_successful(input, true, SOME_IDENT, kw::some)?;
+                } else {
+                    parsed.push(String::from(WHEN_SECTION));
+                }
+
+                when = Some(when_expr);
+            }
+
+            let some_when = when.as_ref().is_some_and(|when| when.ok_when);
+
+            while !input.is_empty() {
+                let section = parse_section(input, SECTIONS)?;
+
+                first_section.get_or_insert_with(|| section.span());
+
+                check_binding(when.as_ref(), &section, SOME_SECTION)?;
+
+                check_duplicate(&mut parsed, &section)?;
+
+                match section.to_string().as_str() {
+                    WHEN_SECTION =>
+                        when = Some(parse_when(input, kw::some, SOME_SECTION)?),
+                    SOME_SECTION =>
+                        some = parse_successful(input, false, SOME_IDENT, kw::some)?,
+                    OTHERWISE_SECTION =>
+                        otherwise = parse_successful(input, false, SOME_IDENT, kw::otherwise)?
+                            .map(|otherwise| (section, otherwise)),
+                    MAP_SECTION =>
+                        map = parse_map(input)?,
+                    DEBUG_SECTION =>
+                        debug = parse_debug(input, None)?,
+                    WARN_SECTION =>
+                        warn = parse_warn(input, None)?,
+                    CONTINUE_SECTION | BREAK_SECTION | EXIT_SECTION | PANIC_SECTION |
+                    RETURN_SECTION =>
+                        escape = parse_escape(input, escape.as_ref())?,
+                    FINALLY_SECTION =>
+                        finally = parse_finally(input)?,
+                    _ =>
+                        none = parse_failed(input, kw::none)?,
+                }
+            }
+
+            let when = attach_escape(when.ok_or_else(|| Error::new(
+                first_section.unwrap_or_else(Span::call_site),
+                "missing `@when` section, e.g. `@when foo()`",
+            ))?, escape)?;
+
+            let otherwise = match otherwise {
+                Some((section, _)) if some.is_none() || some_when => return Err(Error::new(
+                    section.span(), "`@otherwise` requires a `@some` section",
+                )),
+                otherwise => otherwise.map(|(_, otherwise)| otherwise),
+            };
+
+            check_guarded(&some, otherwise.as_ref(), SOME_SECTION)?;
+
+            let map = check_map(&when, map, SOME_SECTION)?;
+
+            Ok(OptionMacro { when, some, otherwise, map, debug, warn, none, finally })
         }
     }
 }
@@ -4,9 +4,17 @@ bitflags! {
         const SOME    = 0b00001;
         const DEBUG   = 0b00010;
         const NONE    = 0b00100;
-        const SOME_DEBUG      = Self::SOME.bits  | Self::DEBUG.bits;
-        const SOME_NONE       = Self::SOME.bits  | Self::NONE.bits;
-        const SOME_DEBUG_NONE = Self::SOME.bits  | Self::DEBUG.bits     | Self::NONE.bits;
-        const DEBUG_NONE      = Self::DEBUG.bits | Self::NONE.bits;
+        const WARN    = 0b01000;
+        const SOME_DEBUG           = Self::SOME.bits  | Self::DEBUG.bits;
+        const SOME_NONE            = Self::SOME.bits  | Self::NONE.bits;
+        const SOME_WARN            = Self::SOME.bits  | Self::WARN.bits;
+        const SOME_DEBUG_NONE      = Self::SOME.bits  | Self::DEBUG.bits     | Self::NONE.bits;
+        const SOME_DEBUG_WARN      = Self::SOME.bits  | Self::DEBUG.bits     | Self::WARN.bits;
+        const SOME_WARN_NONE       = Self::SOME.bits  | Self::WARN.bits      | Self::NONE.bits;
+        const SOME_DEBUG_WARN_NONE = Self::SOME_DEBUG_NONE.bits              | Self::WARN.bits;
+        const DEBUG_NONE           = Self::DEBUG.bits | Self::NONE.bits;
+        const DEBUG_WARN           = Self::DEBUG.bits | Self::WARN.bits;
+        const WARN_NONE            = Self::WARN.bits  | Self::NONE.bits;
+        const DEBUG_WARN_NONE      = Self::DEBUG.bits | Self::WARN.bits      | Self::NONE.bits;
     }
 }
@@ -1,10 +1,13 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-
-use crate::common::{Capture, OnFail, OnSuccess, WhenExpr};
-#[cfg(all(debug_assertions, feature = "option-debug"))]
-use crate::common::Message;
-use crate::common::tokenize::build_captured;
+use syn::Error;
+use syn::spanned::Spanned;
+
+use crate::common::{Capture, Message, OnFail, OnSuccess, WhenExpr};
+use crate::common::tokenize::{
+    build_captured, build_debug_message, build_escape, build_finally, build_guard,
+    build_mapped_when, build_scrutinee, build_warn_message, wrap_binding, wrap_finally,
+};
 use crate::common::tracing::trace_expansion;
 use crate::option_macro::OptionMacro;
 use crate::option_macro::parts::Parts;
@@ -12,136 +15,166 @@ use crate::option_macro::parts::Parts;
 impl ToTokens for OptionMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(trace_expansion({
-            let when = &self.when;
-
-            match self.definition() {
-                #[cfg(not(all(debug_assertions, feature = "option-debug")))]
-                Parts::SOME |
-                Parts::SOME_DEBUG =>
-                    branch_only_some(when, self.some.as_ref().unwrap()),
-                #[cfg(all(debug_assertions, feature = "option-debug"))]
+            let mapped = self.map.as_ref().map(|map| build_mapped_when(&self.when, map));
+            let when = mapped.as_ref().unwrap_or(&self.when);
+            let otherwise = self.otherwise.as_ref();
+            let finally = build_finally(self.finally.as_ref());
+
+            let expansion = match self.definition() {
                 Parts::SOME =>
-                    branch_only_some(when, self.some.as_ref().unwrap()),
-                #[cfg(not(all(debug_assertions, feature = "option-debug")))]
-                Parts::DEBUG =>
-                    branch_only_none(when, TokenStream::new),
-                #[cfg(all(debug_assertions, feature = "option-debug"))]
-                Parts::DEBUG =>
-                    branch_only_none(when, || build_message_stdout(self.debug.as_ref().unwrap())),
+                    branch_only_some(when, self.some.as_ref().unwrap(), otherwise, &finally),
+                Parts::DEBUG | Parts::WARN | Parts::DEBUG_WARN =>
+                    branch_only_none(when, &finally, || build_output(self)),
                 Parts::NONE =>
-                    branch_only_none(when, || build_on_none(self.none.as_ref().unwrap())),
-                #[cfg(all(debug_assertions, feature = "option-debug"))]
-                Parts::SOME_DEBUG =>
+                    branch_only_none(
+                        when, &finally, || build_on_none(self.none.as_ref().unwrap()),
+                    ),
+                Parts::SOME_DEBUG | Parts::SOME_WARN | Parts::SOME_DEBUG_WARN =>
                     branch_some_or_none(
-                        when, self.some.as_ref().unwrap(),
-                        || build_message_stdout(self.debug.as_ref().unwrap()),
+                        when, self.some.as_ref().unwrap(), otherwise, &finally,
+                        || build_output(self),
                     ),
                 Parts::SOME_NONE =>
                     branch_some_or_none(
-                        when, self.some.as_ref().unwrap(),
+                        when, self.some.as_ref().unwrap(), otherwise, &finally,
                         || build_on_none(self.none.as_ref().unwrap()),
                     ),
-                Parts::SOME_DEBUG_NONE =>
+                Parts::SOME_DEBUG_NONE | Parts::SOME_WARN_NONE | Parts::SOME_DEBUG_WARN_NONE =>
                     branch_some_or_none(
-                        when, self.some.as_ref().unwrap(), || build_debugged_none(self),
+                        when, self.some.as_ref().unwrap(), otherwise, &finally,
+                        || build_debugged_none(self),
                     ),
-                Parts::DEBUG_NONE =>
-                    branch_only_none(when, || build_debugged_none(self)),
-                _ => unimplemented!("{:?} is not supported", self.definition())
-            }
+                Parts::DEBUG_NONE | Parts::WARN_NONE | Parts::DEBUG_WARN_NONE =>
+                    branch_only_none(when, &finally, || build_debugged_none(self)),
+                Parts::NOTHING if when.escape.is_some() =>
+                    branch_only_none(when, &finally, TokenStream::new),
+                _ => Error::new(
+                    when.expr.span(),
+                    "expected at least one `@some`, `@debug`, `@warn` or `@none` section",
+                ).to_compile_error(),
+            };
+
+            wrap_binding(when, wrap_finally(when, expansion, &finally))
         }));
     }
 }
 
 fn branch_some_or_none(
-    when: &WhenExpr, some: &OnSuccess, build_none: impl Fn() -> TokenStream,
+    when: &WhenExpr, some: &OnSuccess, otherwise: Option<&OnSuccess>, finally: &TokenStream,
+    build_none: impl Fn() -> TokenStream,
 ) -> TokenStream {
-    let when_expr = &when.expr;
-    let (some_branch, on_some) = build_on_some(some);
+    let when_expr = build_scrutinee(when);
     let on_none = build_none();
-    let tried = if when.tried { quote! { ; return None; } } else { TokenStream::new() };
+    let tried = build_tried_none(when, finally);
+    let some_branches = build_some_branches(some, otherwise);
 
     quote! {
         match #when_expr {
-            #some_branch => { #on_some }
+            #some_branches
             None => { #on_none #tried }
         }
     }
 }
 
-fn branch_only_none(when: &WhenExpr, build_none: impl Fn() -> TokenStream) -> TokenStream {
-    let when_expr = &when.expr;
+fn branch_only_none(
+    when: &WhenExpr, finally: &TokenStream, build_none: impl Fn() -> TokenStream,
+) -> TokenStream {
+    let when_expr = build_scrutinee(when);
     let on_none = build_none();
 
-    if when.tried {
-        quote! { if #when_expr.is_none() { #on_none; return None; } }
+    if when.tried && on_none.is_empty() && finally.is_empty() {
+        quote! { (#when_expr)?; }
+    } else if when.diverges() {
+        let tried = build_tried_none(when, finally);
+
+        quote! { match #when_expr { Some(_) => {} None => { #on_none #tried } } }
     } else {
-        quote! { if #when_expr.is_none() { #on_none } }
+        quote! { if (#when_expr).is_none() { #on_none } }
     }
 }
 
-fn branch_only_some(when: &WhenExpr, some: &OnSuccess) -> TokenStream {
-    let when_expr = &when.expr;
-    let (captured, on_some) = match some {
-        OnSuccess::Expr(expr) =>
-            (&expr.captured, expr.expr.to_token_stream()),
-        OnSuccess::Message(message) => {
-            let message_fmt = message.build_message();
-            let some_message = quote! { println!(#message_fmt); };
-
-            (&message.captured, some_message)
-        }
-    };
+fn branch_only_some(
+    when: &WhenExpr, some: &OnSuccess, otherwise: Option<&OnSuccess>, finally: &TokenStream,
+) -> TokenStream {
+    let when_expr = build_scrutinee(when);
 
-    if when.tried {
-        let some_branch = build_branch_some(captured);
+    if otherwise.is_none() && !when.diverges() &&
+        some.captured().is_none_or(|captured| captured.guard.is_none()) {
+        let on_some = match some {
+            OnSuccess::Expr(expr) => expr.expr.to_token_stream(),
+            OnSuccess::Message(message) => {
+                let message_fmt = message.build_message();
 
-        quote! {
-            match #when_expr {
-                #some_branch => { #on_some }
-                None => { return None; }
+                quote! { println!(#message_fmt); }
             }
+        };
+
+        return if some.captured().is_some() {
+            let captured = build_captured(some.captured());
+
+            quote! { match #when_expr { Some(#captured) => { #on_some; } None => {} } }
+        } else {
+            quote! { if (#when_expr).is_some() { #on_some; } }
+        };
+    }
+
+    let tried = build_tried_none(when, finally);
+    let some_branches = build_some_branches(some, otherwise);
+
+    quote! {
+        match #when_expr {
+            #some_branches
+            None => { #tried }
         }
-    } else if captured.is_some() {
-        let captured = build_captured(captured);
+    }
+}
 
-        quote! { if let Some(#captured) = #when_expr { #on_some; } }
-    } else {
-        quote! { if #when_expr.is_some() { #on_some; } }
+fn build_tried_none(when: &WhenExpr, finally: &TokenStream) -> TokenStream {
+    match (&when.escape, when.tried) {
+        (Some(escape), _) => {
+            let escape = build_escape(escape, finally);
+
+            quote! { ; #escape }
+        }
+        (None, true) => quote! { ; #finally return None; },
+        (None, false) => TokenStream::new(),
     }
 }
 
-fn build_branch_some(captured: &Option<Capture>) -> TokenStream {
+fn build_branch_some(captured: Option<&Capture>) -> TokenStream {
     let capture = build_captured(captured);
+    let guard = build_guard(captured);
 
-    quote! { Some(#capture) }
+    quote! { Some(#capture) #guard }
 }
 
 fn build_debugged_none(result_macro: &OptionMacro) -> TokenStream {
-    cfg_if! {
-        if #[cfg(all(debug_assertions, feature = "option-debug"))] {
-            let on_none = build_on_none(result_macro.none.as_ref().unwrap());
-            let on_debug = build_message_stdout(result_macro.debug.as_ref().unwrap());
+    let on_none = build_on_none(result_macro.none.as_ref().unwrap());
+    let on_output = build_output(result_macro);
 
-            quote! { #on_debug  #on_none }
-        } else {
-            build_on_none(result_macro.none.as_ref().unwrap())
-        }
-    }
+    quote! { #on_output  #on_none }
+}
+
+fn build_output(result_macro: &OptionMacro) -> TokenStream {
+    let on_debug = result_macro.debug.as_ref().map_or_else(TokenStream::new, build_message_stdout);
+    let on_warn = result_macro.warn.as_ref().map_or_else(TokenStream::new, build_message_stderr);
+
+    quote! { #on_debug  #on_warn }
 }
 
-#[cfg(all(debug_assertions, feature = "option-debug"))]
 fn build_message_stdout(message: &Message) -> TokenStream {
-    let message_fmt = message.build_message();
+    build_debug_message(message, "option-debug")
+}
 
-    quote! { println!(#message_fmt); }
+fn build_message_stderr(message: &Message) -> TokenStream {
+    build_warn_message(message, "option-warn")
 }
 
 fn build_on_none(error: &OnFail) -> TokenStream {
     let mut on_none = TokenStream::new();
 
-    if error.message.is_some() {
-        let message_fmt = error.message.as_ref().unwrap().build_message();
+    if let Some(message) = &error.message {
+        let message_fmt = message.build_message();
 
         on_none.extend(quote! { eprintln!(#message_fmt); });
     }
@@ -155,6 +188,22 @@ fn build_on_none(error: &OnFail) -> TokenStream {
     on_none
 }
 
+fn build_some_branches(some: &OnSuccess, otherwise: Option<&OnSuccess>) -> TokenStream {
+    let (some_branch, on_some) = build_on_some(some);
+    let mut some_branches = quote! { #some_branch => { #on_some } };
+
+    if let Some(otherwise) = otherwise {
+        let (otherwise_branch, on_otherwise) = build_on_some(otherwise);
+
+        some_branches.extend(quote! {
+            #[allow(unreachable_patterns)]
+            #otherwise_branch => { #on_otherwise }
+        });
+    }
+
+    some_branches
+}
+
 fn build_on_some(some: &OnSuccess) -> (TokenStream, TokenStream) {
     let (some_captured, on_some) = match some {
         OnSuccess::Message(message) => {
@@ -164,7 +213,7 @@ fn build_on_some(some: &OnSuccess) -> (TokenStream, TokenStream) {
         }
         OnSuccess::Expr(expr) => (&expr.captured, expr.expr.to_token_stream())
     };
-    let some_branch = build_branch_some(some_captured);
+    let some_branch = build_branch_some(some_captured.as_ref());
 
     (some_branch, on_some)
 }
@@ -1,7 +1,11 @@
 #[cfg(feature = "trace")]
 use std::fmt::{self, Display, Formatter};
 
-use crate::common::{Message, OnFail, OnSuccess, WhenExpr};
+#[cfg(feature = "trace")]
+use quote::ToTokens;
+use syn::Expr;
+
+use crate::common::{Capture, MapErr, Message, OnFail, OnFailArm, OnSuccess, Retry, WhenExpr};
 #[cfg(feature = "trace")]
 use crate::display;
 use crate::result_macro::parts::Parts;
@@ -12,30 +16,53 @@ mod tokenize;
 
 pub struct ResultMacro {
     when: WhenExpr,
-    ok: Option<OnSuccess>,
+    ok: Vec<OnSuccess>,
+    otherwise: Option<OnSuccess>,
+    map: Option<Expr>,
     debug: Option<Message>,
+    warn: Option<Message>,
     error: Option<OnFail>,
+    error_arms: Vec<OnFailArm>,
+    err_capture: Capture,
+    map_err: Option<MapErr>,
+    retry: Option<Retry>,
+    finally: Option<Expr>,
 }
 
 #[cfg(feature = "trace")]
 impl Display for ResultMacro {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        let ok = display(&self.ok);
+        let ok = self.ok.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+        let otherwise = display(&self.otherwise);
+        let map = display(&self.map.as_ref().map(ToTokens::to_token_stream));
         let debug = display(&self.debug);
+        let warn = display(&self.warn);
         let error = display(&self.error);
+        let error_arms = self.error_arms.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let map_err = display(&self.map_err);
+        let retry = display(&self.retry);
+        let finally = display(&self.finally.as_ref().map(ToTokens::to_token_stream));
 
         write!(
             fmt,
-            "result! {{\n  when: {ok},\n  ok: {debug},\n  debug: {error},\n  error: {}\n}}",
-            self.when
+            "result! {{\n  when: {},\n  ok: [{ok}],\n  otherwise: {otherwise},\n  map: {map},\n  debug: {debug},\n  warn: {warn},\n  error: {error},\n  error_arms: [{error_arms}],\n  err_capture: {},\n  map_err: {map_err},\n  retry: {retry},\n  finally: {finally}\n}}",
+            self.when, self.err_capture
         )
     }
 }
 
 impl ResultMacro {
     fn definition(&self) -> Parts {
-        (if self.ok.is_some() { Parts::OK } else { Parts::NONE }) |
+        (if self.ok.is_empty() { Parts::NONE } else { Parts::OK }) |
             (if self.debug.is_some() { Parts::DEBUG } else { Parts::NONE }) |
-            (if self.error.is_some() { Parts::ERROR } else { Parts::NONE })
+            (if self.warn.is_some() { Parts::WARN } else { Parts::NONE }) |
+            (if self.error.is_none() && self.error_arms.is_empty() {
+                Parts::NONE
+            } else {
+                Parts::ERROR
+            })
     }
 }
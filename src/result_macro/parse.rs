@@ -1,19 +1,41 @@
+use proc_macro2::{Ident, Span};
+use syn::Error;
 use syn::parse::{Parse, ParseStream};
 
-use crate::common::Capture;
-use crate::common::parse::{parse_debug, parse_failed, parse_successful, parse_when};
+use crate::common::{Capture, MapErr, OnFail, OnFailArm, WhenExpr};
+use crate::common::parse::{
+    attach_escape, BREAK_SECTION, capture_escape, capture_failed, capture_message, check_binding,
+    check_duplicate, check_guarded, check_map, check_repeated, CONTINUE_SECTION, declare_capture,
+    DEBUG_SECTION, EXIT_SECTION, FINALLY_SECTION, MAP_SECTION, PANIC_SECTION, parse_context,
+    parse_debug, parse_escape, parse_failed_arm, parse_finally, parse_map, parse_map_err,
+    parse_retry, parse_section, parse_successful, parse_warn, parse_when, peek_when, RETURN_SECTION,
+    WARN_SECTION, WHEN_SECTION,
+};
 use crate::common::tracing::{trace_parsed, trace_source};
 use crate::result_macro::ResultMacro;
 
 mod kw {
+    custom_keyword![context];
     custom_keyword![error];
+    custom_keyword![map_err];
     custom_keyword![ok];
+    custom_keyword![otherwise];
+    custom_keyword![retry];
 }
 
+const CONTEXT_SECTION: &str = "context";
 const ERR_IDENT: &str = "err";
 const ERROR_SECTION: &str = "error";
+const MAP_ERR_SECTION: &str = "map_err";
 const OK_IDENT: &str = "ok";
 const OK_SECTION: &str = "ok";
+const OTHERWISE_SECTION: &str = "otherwise";
+const RETRY_SECTION: &str = "retry";
+const SECTIONS: &[&str] = &[
+    WHEN_SECTION, OK_SECTION, OTHERWISE_SECTION, MAP_SECTION, DEBUG_SECTION, WARN_SECTION,
+    ERROR_SECTION, RETRY_SECTION, MAP_ERR_SECTION, CONTEXT_SECTION, CONTINUE_SECTION,
+    BREAK_SECTION, EXIT_SECTION, PANIC_SECTION, RETURN_SECTION, FINALLY_SECTION,
+];
 
 impl Parse for ResultMacro {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -21,15 +43,157 @@ impl Parse for ResultMacro {
 
         #[inline]
         fn parse(input: ParseStream) -> syn::Result<ResultMacro> {
-            let when = parse_when(input, kw::ok)?;
-            let err_capture = Some(Capture::from(ERR_IDENT));
+            let mut parsed = Vec::new();
+            let mut first_section = None;
+            let mut when = None;
+            let mut ok = Vec::new();
+            let mut otherwise = None;
+            let mut map = None;
+            let mut declared = None;
+            let mut debug = None;
+            let mut warn = None;
+            let mut error_arms = Vec::new();
+            let mut map_err = None;
+            let mut retry = None;
+            let mut escape = None;
+            let mut finally = None;
+
+            if peek_when(input, OK_SECTION) {
+                let when_expr = parse_when(input, kw::ok, OK_SECTION)?;
+
+                if when_expr.ok_when {
+                    parsed.push(String::from(OK_SECTION));
+                    ok.extend(parse_successful(input, true, OK_IDENT, kw::ok)?);
+                } else {
+                    parsed.push(String::from(WHEN_SECTION));
+                }
+
+                when = Some(when_expr);
+            }
+
+            let ok_when = when.as_ref().is_some_and(|when| when.ok_when);
+
+            while !input.is_empty() {
+                let section = parse_section(input, SECTIONS)?;
+
+                first_section.get_or_insert_with(|| section.span());
+
+                check_binding(when.as_ref(), &section, OK_SECTION)?;
+
+                if section == ERROR_SECTION || (section == OK_SECTION && !ok_when) {
+                    check_repeated(&mut parsed, &section, input)?;
+                } else {
+                    check_duplicate(&mut parsed, &section)?;
+                }
+
+                check_map_err(&section, map_err.as_ref())?;
+
+                match section.to_string().as_str() {
+                    WHEN_SECTION =>
+                        when = Some(parse_when(input, kw::ok, OK_SECTION)?),
+                    OK_SECTION =>
+                        ok.extend(parse_successful(input, false, OK_IDENT, kw::ok)?),
+                    OTHERWISE_SECTION =>
+                        otherwise = parse_successful(input, false, OK_IDENT, kw::otherwise)?
+                            .map(|otherwise| (section, otherwise)),
+                    MAP_SECTION =>
+                        map = parse_map(input)?,
+                    DEBUG_SECTION =>
+                        debug = parse_debug(input, Some(&mut declared))?,
+                    WARN_SECTION =>
+                        warn = parse_warn(input, Some(&mut declared))?,
+                    RETRY_SECTION =>
+                        retry = parse_retry(input, kw::retry)?,
+                    MAP_ERR_SECTION =>
+                        map_err = parse_map_err(input, kw::map_err)?
+                            .map(|map_err| (section, map_err)),
+                    CONTEXT_SECTION =>
+                        map_err = parse_context(input, kw::context)?
+                            .map(|context| (section, context)),
+                    CONTINUE_SECTION | BREAK_SECTION | EXIT_SECTION | PANIC_SECTION |
+                    RETURN_SECTION =>
+                        escape = parse_escape(input, escape.as_ref())?,
+                    FINALLY_SECTION =>
+                        finally = parse_finally(input)?,
+                    _ =>
+                        error_arms.extend(parse_failed_arm(input, kw::error)?),
+                }
+            }
+
+            let when = when.ok_or_else(|| Error::new(
+                first_section.unwrap_or_else(Span::call_site),
+                "missing `@when` section, e.g. `@when foo()`",
+            ))?;
+
+            let otherwise = match otherwise {
+                Some((section, _)) if ok.is_empty() || ok_when => return Err(Error::new(
+                    section.span(), "`@otherwise` requires an `@ok` section",
+                )),
+                otherwise => otherwise.map(|(_, otherwise)| otherwise),
+            };
+
+            check_guarded(&ok, otherwise.as_ref(), OK_SECTION)?;
+
+            let map = check_map(&when, map, OK_SECTION)?;
+            let map_err = tried_map_err(&when, map_err)?;
+
+            let mut error = single_error_arm(&mut error_arms, &mut declared)?;
+            let err_capture = declared.unwrap_or_else(|| Capture::from(ERR_IDENT));
+
+            for message in [&mut debug, &mut warn].into_iter().flatten() {
+                capture_message(message, &err_capture);
+            }
+
+            if let Some((_, escape)) = &mut escape {
+                capture_escape(escape, &err_capture);
+            }
+
+            if let Some(error) = &mut error {
+                capture_failed(error, &err_capture);
+            }
+
+            let when = attach_escape(when, escape)?;
 
             Ok(ResultMacro {
-                ok: parse_successful(input, &when, OK_IDENT, OK_SECTION, kw::ok)?,
-                when,
-                debug: parse_debug(input, &err_capture)?,
-                error: parse_failed(input, kw::error, ERROR_SECTION, err_capture)?,
+                when, ok, otherwise, map, debug, warn, error, error_arms, err_capture, map_err,
+                retry, finally,
             })
         }
     }
 }
+
+fn single_error_arm(
+    error_arms: &mut Vec<OnFailArm>, declared: &mut Option<Capture>,
+) -> syn::Result<Option<OnFail>> {
+    match error_arms.as_slice() {
+        [OnFailArm { pattern, .. }] if pattern.as_ref().is_none_or(Capture::is_declaration) => {
+            let arm = error_arms.pop().unwrap();
+
+            if let Some(pattern) = arm.pattern {
+                declare_capture(declared, pattern)?;
+            }
+
+            Ok(Some(arm.failed))
+        }
+        _ => Ok(None)
+    }
+}
+
+fn check_map_err(section: &Ident, map_err: Option<&(Ident, MapErr)>) -> syn::Result<()> {
+    match (section.to_string().as_str(), map_err) {
+        (MAP_ERR_SECTION | CONTEXT_SECTION, Some((mapped, _))) => Err(Error::new(
+            section.span(), format!("`@{section}` can not be combined with `@{mapped}`"),
+        )),
+        _ => Ok(())
+    }
+}
+
+fn tried_map_err(when: &WhenExpr, map_err: Option<(Ident, MapErr)>) -> syn::Result<Option<MapErr>> {
+    match map_err {
+        Some((section, _)) if !when.tried => Err(Error::new(
+            section.span(),
+            format!("`@{section}` requires a tried `@when` expression, e.g. `@when foo()?`"),
+        )),
+        map_err => Ok(map_err.map(|(_, map_err)| map_err)),
+    }
+}
@@ -4,9 +4,17 @@ bitflags! {
         const OK    = 0b00001;
         const DEBUG = 0b00010;
         const ERROR = 0b00100;
-        const OK_DEBUG       = Self::OK.bits    | Self::DEBUG.bits;
-        const OK_ERROR       = Self::OK.bits    | Self::ERROR.bits;
-        const OK_DEBUG_ERROR = Self::OK.bits    | Self::DEBUG.bits     | Self::ERROR.bits;
-        const DEBUG_ERROR    = Self::DEBUG.bits | Self::ERROR.bits;
+        const WARN  = 0b01000;
+        const OK_DEBUG            = Self::OK.bits    | Self::DEBUG.bits;
+        const OK_ERROR            = Self::OK.bits    | Self::ERROR.bits;
+        const OK_WARN             = Self::OK.bits    | Self::WARN.bits;
+        const OK_DEBUG_ERROR      = Self::OK.bits    | Self::DEBUG.bits     | Self::ERROR.bits;
+        const OK_DEBUG_WARN       = Self::OK.bits    | Self::DEBUG.bits     | Self::WARN.bits;
+        const OK_WARN_ERROR       = Self::OK.bits    | Self::WARN.bits      | Self::ERROR.bits;
+        const OK_DEBUG_WARN_ERROR = Self::OK_DEBUG_ERROR.bits               | Self::WARN.bits;
+        const DEBUG_ERROR         = Self::DEBUG.bits | Self::ERROR.bits;
+        const DEBUG_WARN          = Self::DEBUG.bits | Self::WARN.bits;
+        const WARN_ERROR          = Self::WARN.bits  | Self::ERROR.bits;
+        const DEBUG_WARN_ERROR    = Self::DEBUG.bits | Self::WARN.bits      | Self::ERROR.bits;
     }
 }
@@ -1,10 +1,15 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
+use syn::{Error, Expr};
+use syn::spanned::Spanned;
 
-use crate::common::{Capture, OnFail, OnSuccess, WhenExpr};
-#[cfg(all(debug_assertions, feature = "result-debug"))]
-use crate::common::Message;
-use crate::common::tokenize::build_captured;
+use crate::common::{
+    Capture, Escape, MapErr, Message, OnFail, OnFailArm, OnSuccess, Retry, WhenExpr,
+};
+use crate::common::tokenize::{
+    build_captured, build_debug_message, build_escape, build_finally, build_guard,
+    build_mapped_when, build_scrutinee, build_warn_message, wrap_binding, wrap_finally,
+};
 use crate::common::tracing::trace_expansion;
 use crate::result_macro::parts::Parts;
 use crate::result_macro::ResultMacro;
@@ -12,164 +17,281 @@ use crate::result_macro::ResultMacro;
 impl ToTokens for ResultMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(trace_expansion({
-            let when = &self.when;
-
-            match self.definition() {
-                #[cfg(not(all(debug_assertions, feature = "result-debug")))]
-                Parts::OK |
-                Parts::OK_DEBUG =>
-                    branch_only_ok(when, self.ok.as_ref().unwrap()),
-                #[cfg(all(debug_assertions, feature = "result-debug"))]
+            let retried = self.retry.as_ref().map(|retry| build_retried_when(self, retry));
+            let when = retried.as_ref().unwrap_or(&self.when);
+            let mapped = self.map.as_ref().map(|map| build_mapped_when(when, map));
+            let when = mapped.as_ref().unwrap_or(when);
+            let ok = &self.ok;
+            let otherwise = self.otherwise.as_ref();
+            let err = &self.err_capture;
+            let finally = build_finally(self.finally.as_ref());
+            let tried = when.escape.as_ref().map_or_else(
+                || build_tried_error(self.map_err.as_ref(), &finally),
+                |escape| build_escape(escape, &finally),
+            );
+
+            let expansion = match self.definition() {
                 Parts::OK =>
-                    branch_only_ok(when, self.ok.as_ref().unwrap()),
-                #[cfg(not(all(debug_assertions, feature = "result-debug")))]
-                Parts::DEBUG =>
-                    branch_only_error(when, || (&None, TokenStream::new())),
-                #[cfg(all(debug_assertions, feature = "result-debug"))]
-                Parts::DEBUG =>
-                    branch_only_error(when, || build_message_stdout(self.debug.as_ref().unwrap())),
+                    branch_only_ok(when, ok, otherwise, &tried),
+                Parts::DEBUG | Parts::WARN | Parts::DEBUG_WARN =>
+                    branch_only_error(when, err, &tried, || build_output(self)),
                 Parts::ERROR =>
-                    branch_only_error(when, || build_on_error(self.error.as_ref().unwrap())),
-                #[cfg(all(debug_assertions, feature = "result-debug"))]
-                Parts::OK_DEBUG =>
-                    branch_ok_or_error(
-                        when, self.ok.as_ref().unwrap(),
-                        || build_message_stdout(self.debug.as_ref().unwrap()),
-                    ),
+                    branch_only_error(when, err, &tried, || build_on_error(self)),
+                Parts::OK_DEBUG | Parts::OK_WARN | Parts::OK_DEBUG_WARN =>
+                    branch_ok_or_error(when, ok, otherwise, err, &tried, || build_output(self)),
                 Parts::OK_ERROR =>
+                    branch_ok_or_error(when, ok, otherwise, err, &tried, || build_on_error(self)),
+                Parts::OK_DEBUG_ERROR | Parts::OK_WARN_ERROR | Parts::OK_DEBUG_WARN_ERROR =>
                     branch_ok_or_error(
-                        when, self.ok.as_ref().unwrap(),
-                        || build_on_error(self.error.as_ref().unwrap()),
-                    ),
-                Parts::OK_DEBUG_ERROR =>
-                    branch_ok_or_error(
-                        when, self.ok.as_ref().unwrap(), || build_debugged_error(self),
+                        when, ok, otherwise, err, &tried, || build_debugged_error(self),
                     ),
-                Parts::DEBUG_ERROR =>
-                    branch_only_error(when, || build_debugged_error(self)),
-                _ => unimplemented!("{:?} is not supported", self.definition())
-            }
+                Parts::DEBUG_ERROR | Parts::WARN_ERROR | Parts::DEBUG_WARN_ERROR =>
+                    branch_only_error(when, err, &tried, || build_debugged_error(self)),
+                Parts::NONE
+                if self.map_err.is_some() || self.retry.is_some() || when.escape.is_some() =>
+                    branch_only_error(when, err, &tried, || (false, TokenStream::new())),
+                _ => Error::new(
+                    when.expr.span(),
+                    "expected at least one `@ok`, `@debug`, `@warn` or `@error` section",
+                ).to_compile_error(),
+            };
+
+            let expansion = wrap_retry(self.retry.as_ref(), expansion);
+
+            wrap_binding(when, wrap_finally(when, expansion, &finally))
         }));
     }
 }
 
-fn branch_ok_or_error<'a>(
-    when: &'a WhenExpr, ok: &OnSuccess,
-    build_error: impl Fn() -> (&'a Option<Capture>, TokenStream),
+fn branch_ok_or_error(
+    when: &WhenExpr, ok: &[OnSuccess], otherwise: Option<&OnSuccess>, err: &Capture,
+    tried: &TokenStream, build_error: impl Fn() -> (bool, TokenStream),
 ) -> TokenStream {
-    let when_expr = &when.expr;
-    let (ok_branch, on_ok) = build_on_ok(ok);
+    let when_expr = build_scrutinee(when);
+    let ok_branches = build_ok_branches(ok, otherwise);
     let (captured, on_error) = build_error();
-    let error_branch = if captured.is_some() || when.tried {
-        quote! { Err(err) }
-    } else {
-        quote! { Err(_) }
-    };
-    let tried = if when.tried { quote! { ; return Err(err); } } else { TokenStream::new() };
+    let (error_branch, on_error) = build_error_branch(when, err, captured, &on_error, tried);
 
     quote! {
         match #when_expr {
-            #ok_branch => { #on_ok }
-            #error_branch => { #on_error #tried }
+            #ok_branches
+            #error_branch => { #on_error }
         }
     }
 }
 
-fn branch_only_error<'a>(
-    when: &'a WhenExpr, build_error: impl Fn() -> (&'a Option<Capture>, TokenStream),
+fn branch_only_error(
+    when: &WhenExpr, err: &Capture, tried: &TokenStream,
+    build_error: impl Fn() -> (bool, TokenStream),
 ) -> TokenStream {
-    let when_expr = &when.expr;
+    let when_expr = build_scrutinee(when);
     let (captured, on_error) = build_error();
-    let when_tried = if when.tried { quote! { ; return Err(err); } } else { TokenStream::new() };
 
-    if captured.is_some() || when.tried {
-        quote! { if let Err(err) = #when_expr { #on_error #when_tried } }
+    if captured || when.diverges() {
+        let (error_branch, on_error) =
+            build_error_branch(when, err, captured, &on_error, tried);
+
+        quote! { if let #error_branch = #when_expr { #on_error } }
     } else {
-        quote! { if #when_expr.is_err() { #on_error } }
+        quote! { if (#when_expr).is_err() { #on_error } }
     }
 }
 
-fn branch_only_ok(when: &WhenExpr, ok: &OnSuccess) -> TokenStream {
-    let when_expr = &when.expr;
-    let (captured, on_ok) = match ok {
-        OnSuccess::Expr(expr) =>
-            (&expr.captured, expr.expr.to_token_stream()),
-        OnSuccess::Message(message) => {
-            let message_fmt = message.build_message();
-            let ok_message = quote! { println!(#message_fmt); };
+fn branch_only_ok(
+    when: &WhenExpr, ok: &[OnSuccess], otherwise: Option<&OnSuccess>, tried: &TokenStream,
+) -> TokenStream {
+    let when_expr = build_scrutinee(when);
 
-            (&message.captured, ok_message)
+    if let ([ok], None, false) = (ok, otherwise, when.diverges()) {
+        if ok.captured().is_none_or(|captured| captured.guard.is_none()) {
+            let on_ok = match ok {
+                OnSuccess::Expr(expr) => expr.expr.to_token_stream(),
+                OnSuccess::Message(message) => {
+                    let message_fmt = message.build_message();
+
+                    quote! { println!(#message_fmt); }
+                }
+            };
+
+            return if ok.captured().is_some() {
+                let captured = build_captured(ok.captured());
+
+                quote! { match #when_expr { Ok(#captured) => { #on_ok; } Err(_) => {} } }
+            } else {
+                quote! { if (#when_expr).is_ok() { #on_ok; } }
+            };
         }
-    };
+    }
 
-    if when.tried {
-        let ok_branch = build_branch_ok(captured);
+    let ok_branches = build_ok_branches(ok, otherwise);
+    let (error_branch, on_error) =
+        build_error_branch(when, &Capture::from("err"), false, &TokenStream::new(), tried);
 
-        quote! {
-            match #when_expr {
-                #ok_branch => { #on_ok }
-                Err(err) => { return Err(err); }
-            }
+    quote! {
+        match #when_expr {
+            #ok_branches
+            #error_branch => { #on_error }
         }
-    } else if captured.is_some() {
-        let captured = build_captured(captured);
+    }
+}
+
+fn build_branch_ok(captured: Option<&Capture>) -> TokenStream {
+    let capture = build_captured(captured);
+    let guard = build_guard(captured);
+    // spanned with the user pattern so `unreachable_patterns` reports a shadowed `@ok` arm
+    let span = captured.map_or_else(Span::call_site, |captured| captured.pattern.span());
 
-        quote! { if let Ok(#captured) = #when_expr { #on_ok; } }
+    quote_spanned! { span=> Ok(#capture) #guard }
+}
+
+fn build_error_branch(
+    when: &WhenExpr, err: &Capture, captured: bool, on_error: &TokenStream, tried: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let err = build_captured(Some(err));
+    let captured = captured || when.escape.as_ref().is_some_and(Escape::captured);
+
+    if when.tried {
+        let tried_err = build_tried_err();
+        let borrowed = if captured { quote! { let #err = &#tried_err; } } else { TokenStream::new() };
+
+        (quote! { Err(#tried_err) }, quote! { #borrowed #on_error ; #tried })
     } else {
-        quote! { if #when_expr.is_ok() { #on_ok; } }
+        let on_error =
+            if when.escape.is_some() { quote! { #on_error ; #tried } } else { on_error.clone() };
+
+        (if captured { quote! { Err(#err) } } else { quote! { Err(_) } }, on_error)
     }
 }
 
-fn build_branch_ok(captured: &Option<Capture>) -> TokenStream {
-    let capture = build_captured(captured);
+fn build_retried_when(result_macro: &ResultMacro, retry: &Retry) -> WhenExpr {
+    let when = &result_macro.when;
+    let when_expr = build_scrutinee(when);
+    let attempt = Ident::new("attempt", Span::call_site());
+    let attempts = &retry.attempts;
+    let result = Ident::new("result", Span::mixed_site());
+    let (captured, on_debug) = result_macro.debug.as_ref()
+        .map_or_else(|| (false, TokenStream::new()), build_message_stdout);
+    let err = if captured { build_captured(Some(&result_macro.err_capture)) } else { quote! { _ } };
+    let backoff = retry.backoff.as_ref()
+        .map_or_else(TokenStream::new, |backoff| quote! { ::std::thread::sleep(#backoff); });
 
-    quote! { Ok(#capture) }
+    WhenExpr {
+        expr: Expr::Verbatim(quote! {
+            loop {
+                match #when_expr {
+                    Err(#err) if #attempt < #attempts => { #on_debug #backoff #attempt += 1; }
+                    #result => break #result,
+                }
+            }
+        }),
+        tried: when.tried,
+        ok_when: when.ok_when,
+        escape: when.escape.clone(),
+        binding: when.binding.clone(),
+    }
 }
 
-fn build_debugged_error(result_macro: &ResultMacro) -> (&Option<Capture>, TokenStream) {
-    cfg_if! {
-        if #[cfg(all(debug_assertions, feature = "result-debug"))] {
-            let (captured_err, on_error) = build_on_error(result_macro.error.as_ref().unwrap());
-            let (captured_dbg, on_debug) = build_message_stdout(result_macro.debug.as_ref().unwrap());
-            let captured = if captured_dbg.is_some() { captured_dbg } else { captured_err };
+fn wrap_retry(retry: Option<&Retry>, expansion: TokenStream) -> TokenStream {
+    if retry.is_some() {
+        let attempt = Ident::new("attempt", Span::call_site());
 
-            (captured, quote! { #on_debug  #on_error })
-        } else {
-            build_on_error(result_macro.error.as_ref().unwrap())
-        }
+        quote! { { let mut #attempt: usize = 1; #expansion } }
+    } else {
+        expansion
     }
 }
 
-#[cfg(all(debug_assertions, feature = "result-debug"))]
-fn build_message_stdout(message: &Message) -> (&Option<Capture>, TokenStream) {
-    let message_fmt = message.build_message();
+fn build_tried_err() -> Ident {
+    Ident::new("tried_err", Span::mixed_site())
+}
+
+fn build_tried_error(map_err: Option<&MapErr>, finally: &TokenStream) -> TokenStream {
+    let tried_err = build_tried_err();
+    let tried = match map_err {
+        None =>
+            quote! { Err(::core::convert::From::from(#tried_err)) },
+        Some(MapErr::Context(message)) => {
+            let message_fmt = message.build_message();
+
+            quote! {
+                Err(::core::convert::From::from(
+                    format!("{}: {}", format_args!(#message_fmt), #tried_err)
+                ))
+            }
+        }
+        Some(MapErr::Map(map_err)) =>
+            quote! { Err(#tried_err).map_err(#map_err).map_err(::core::convert::From::from) },
+    };
+
+    quote! { #finally return #tried; }
+}
+
+fn build_debugged_error(result_macro: &ResultMacro) -> (bool, TokenStream) {
+    let (captured_err, on_error) = build_on_error(result_macro);
+    let (captured_out, on_output) = build_output(result_macro);
 
-    (&message.captured, quote! { println!(#message_fmt); })
+    (captured_out || captured_err, quote! { #on_output  #on_error })
 }
 
-fn build_on_error(error: &OnFail) -> (&Option<Capture>, TokenStream) {
-    let mut on_error = TokenStream::new();
-    let mut captured = &None;
+fn build_output(result_macro: &ResultMacro) -> (bool, TokenStream) {
+    let (captured_dbg, on_debug) = result_macro.debug.as_ref()
+        .map_or_else(|| (false, TokenStream::new()), build_message_stdout);
+    let (captured_warn, on_warn) = result_macro.warn.as_ref()
+        .map_or_else(|| (false, TokenStream::new()), build_message_stderr);
 
-    if error.message.is_some() {
-        let (captured_error, message_error) = {
-            let message_fmt = error.message.as_ref().unwrap().build_message();
-            let error_message = quote! { eprintln!(#message_fmt); };
+    (captured_dbg || captured_warn, quote! { #on_debug  #on_warn })
+}
 
-            (&error.message.as_ref().unwrap().captured, error_message)
-        };
+fn build_message_stdout(message: &Message) -> (bool, TokenStream) {
+    (message.captured.is_some(), build_debug_message(message, "result-debug"))
+}
+
+fn build_message_stderr(message: &Message) -> (bool, TokenStream) {
+    (message.captured.is_some(), build_warn_message(message, "result-warn"))
+}
 
-        captured = captured_error;
+fn build_on_error(result_macro: &ResultMacro) -> (bool, TokenStream) {
+    result_macro.error.as_ref().map_or_else(
+        || build_on_error_arms(&result_macro.error_arms, &result_macro.err_capture),
+        build_on_failed,
+    )
+}
 
-        on_error.extend(message_error);
+fn build_on_error_arms(arms: &[OnFailArm], err: &Capture) -> (bool, TokenStream) {
+    let err = err.identifier().unwrap();
+    let arms = arms.iter()
+        .map(|arm| {
+            let pattern = build_captured(arm.pattern.as_ref());
+            let guard = build_guard(arm.pattern.as_ref());
+            let (_, on_error) = build_on_failed(&arm.failed);
+
+            quote! { #pattern #guard => { #on_error } }
+        })
+        .collect::<TokenStream>();
+
+    (true, quote! {
+        match #err {
+            #arms
+        }
+    })
+}
+
+fn build_on_failed(error: &OnFail) -> (bool, TokenStream) {
+    let mut on_error = TokenStream::new();
+    let mut captured = false;
+
+    if let Some(message) = &error.message {
+        let message_fmt = message.build_message();
+
+        captured = message.captured.is_some();
+
+        on_error.extend(quote! { eprintln!(#message_fmt); });
     }
 
     if let Some(expr) = &error.expr {
         let error_expr = &expr.expr;
 
-        if captured.is_none() && expr.captured.is_some() {
-            captured = &expr.captured;
-        }
+        captured |= expr.captured.is_some();
 
         on_error.extend(quote! { #error_expr });
     }
@@ -177,6 +299,27 @@ fn build_on_error(error: &OnFail) -> (&Option<Capture>, TokenStream) {
     (captured, on_error)
 }
 
+fn build_ok_branches(ok: &[OnSuccess], otherwise: Option<&OnSuccess>) -> TokenStream {
+    let mut ok_branches = ok.iter()
+        .map(|ok| {
+            let (ok_branch, on_ok) = build_on_ok(ok);
+
+            quote! { #ok_branch => { #on_ok } }
+        })
+        .collect::<TokenStream>();
+
+    if let Some(otherwise) = otherwise {
+        let (otherwise_branch, on_otherwise) = build_on_ok(otherwise);
+
+        ok_branches.extend(quote! {
+            #[allow(unreachable_patterns)]
+            #otherwise_branch => { #on_otherwise }
+        });
+    }
+
+    ok_branches
+}
+
 fn build_on_ok(ok: &OnSuccess) -> (TokenStream, TokenStream) {
     let (ok_captured, on_ok) = match ok {
         OnSuccess::Message(message) => {
@@ -186,7 +329,7 @@ fn build_on_ok(ok: &OnSuccess) -> (TokenStream, TokenStream) {
         OnSuccess::Expr(expr) => (expr.captured.clone(), expr.expr.to_token_stream())
     };
 
-    let ok_branch = build_branch_ok(&ok_captured);
+    let ok_branch = build_branch_ok(ok_captured.as_ref());
 
     (ok_branch, on_ok)
 }
@@ -0,0 +1,11 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    for input in [Some(1), None] {
+        option! {
+            @when     input
+            @continue
+            @break
+        }
+    }
+}
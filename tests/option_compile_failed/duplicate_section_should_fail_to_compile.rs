@@ -0,0 +1,13 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when foo();
+        @some "first some";
+        @some "second some";
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
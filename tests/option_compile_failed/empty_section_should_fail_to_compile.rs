@@ -0,0 +1,13 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when  foo();
+        @debug
+        @none   "foo failed";
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
@@ -0,0 +1,13 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when foo();
+        @some (n) if n > 0 => "positive: {}", n;
+        @none "nothing";
+    }
+}
+
+fn foo() -> Option<isize> {
+    Some(42)
+}
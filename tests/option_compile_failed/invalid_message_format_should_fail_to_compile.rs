@@ -0,0 +1,12 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when foo();
+        @none "{missing} will not compile";
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
@@ -0,0 +1,9 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when Some(1)
+        @map  |value| value + 1
+        @none "foo is none"
+    }
+}
@@ -0,0 +1,8 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @debug "foo failed";
+        @none   "foo failed";
+    }
+}
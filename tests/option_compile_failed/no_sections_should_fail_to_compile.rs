@@ -0,0 +1,11 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when  foo();
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
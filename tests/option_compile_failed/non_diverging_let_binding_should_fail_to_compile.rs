@@ -0,0 +1,8 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        let _value = @when Some(1)
+        @none "foo is none"
+    }
+}
@@ -0,0 +1,12 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when foo();
+        @some (Some(value)) => "found: {}", value;
+    }
+}
+
+fn foo() -> Option<Option<usize>> {
+    Some(Some(42))
+}
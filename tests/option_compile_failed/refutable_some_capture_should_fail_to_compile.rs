@@ -0,0 +1,12 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when foo();
+        @some (1..=9) => "single digit";
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
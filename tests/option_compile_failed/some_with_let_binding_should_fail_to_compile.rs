@@ -0,0 +1,13 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        let _value = @when foo()
+        @some  "got {}", some
+        @return
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
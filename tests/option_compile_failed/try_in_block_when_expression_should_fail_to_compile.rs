@@ -1,16 +0,0 @@
-use macrofied_toolbox::option;
-
-fn main() {
-    inner().unwrap();
-
-    fn inner() -> Option<usize> {
-        option! {
-            @when { foo()? }
-            @some "will not compile";
-        }
-    }
-}
-
-fn foo() -> Option<usize> {
-    Some(42)
-}
\ No newline at end of file
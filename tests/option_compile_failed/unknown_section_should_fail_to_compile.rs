@@ -0,0 +1,12 @@
+use macrofied_toolbox::option;
+
+fn main() {
+    option! {
+        @when  foo();
+        @error "foo failed";
+    }
+}
+
+fn foo() -> Option<usize> {
+    Some(42)
+}
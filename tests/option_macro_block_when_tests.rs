@@ -0,0 +1,154 @@
+#![cfg(feature = "option")]
+#![allow(unused_assignments)]
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_block_with_try_should_propagate_none() {
+    let expected = None;
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> Option<usize> {
+        let mut actual = "";
+
+        option! {
+            @when  {
+                let value = foo_none()?;
+
+                bar(value)
+            }
+            @some  (value) => { actual = "some"; assert_eq!(42, value); }
+            @none  { actual = "none"; }
+        }
+
+        assert_eq!("this will not be reached", actual);
+
+        Some(0)
+    }
+}
+
+#[test]
+fn when_block_with_try_should_eval_some() {
+    let expected = Some(42);
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> Option<usize> {
+        let mut actual = 0;
+
+        option! {
+            @when  {
+                let value = foo_some()?;
+
+                bar(value)
+            }
+            @some  (value) => { actual = value; }
+        }
+
+        Some(actual)
+    }
+}
+
+#[test]
+fn when_try_block_should_route_none_to_none() {
+    let expected = "none";
+    let mut actual = "";
+
+    option! {
+        @when  try {
+            let value = foo_none()?;
+
+            bar(value)?
+        }
+        @some  "this will not output: {:?}", some
+        @none  { actual = "none"; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_try_block_should_eval_some() {
+    let expected = 42;
+    let mut actual = 0;
+
+    option! {
+        @when  try {
+            let value = foo_some()?;
+
+            bar(value)?
+        }
+        @some  (value) => { actual = value; }
+        @none  "this will not output"
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_tried_try_block_should_eval_none_and_return() {
+    let expected = None;
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> Option<usize> {
+        let mut actual = "";
+
+        option! {
+            @when  try {
+                let value = foo_none()?;
+
+                bar(value)?
+            }?
+            @none  { actual = "none"; }
+        }
+
+        assert_eq!("none", actual);
+
+        Some(0)
+    }
+}
+
+#[test]
+fn when_try_block_with_return_should_return_from_function() {
+    let expected = Some(42);
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> Option<usize> {
+        option! {
+            @when  try {
+                let value = foo_some()?;
+
+                if value > 0 {
+                    return bar(value);
+                }
+
+                value
+            }
+            @some  "this will not output: {}", some
+            @none  "this will not output"
+        }
+
+        Some(0)
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(21)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn bar(value: usize) -> Option<usize> {
+    Some(value * 2)
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "option")]
+
+use std::env;
+use std::process::{Command, Output};
+
+use macrofied_toolbox::option;
+
+const EXIT_CHILD: &str = "OPTION_MACRO_EXIT_CHILD";
+
+#[test]
+fn when_none_option_should_eval_none_then_exit() {
+    if env::var_os(EXIT_CHILD).is_some() {
+        option! {
+            @when foo_none()
+            @none "foo is none"
+            @exit 2
+        }
+
+        unreachable!();
+    }
+
+    let output = exit_child("when_none_option_should_eval_none_then_exit");
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("foo is none\n"));
+}
+
+#[test]
+fn when_none_option_expression_should_exit() {
+    if env::var_os(EXIT_CHILD).is_some() {
+        let _value: usize = option! {
+            @some foo_none()
+            @exit 3
+        };
+
+        unreachable!();
+    }
+
+    let output = exit_child("when_none_option_expression_should_exit");
+
+    assert_eq!(Some(3), output.status.code());
+}
+
+#[test]
+fn when_some_option_expression_should_not_exit() {
+    let expected = 42;
+
+    let actual: usize = option! {
+        @some foo_some()
+        @exit 1
+    };
+
+    assert_eq!(expected, actual);
+}
+
+fn exit_child(test: &str) -> Output {
+    Command::new(env::current_exe().unwrap())
+        .args(["--exact", test, "--nocapture", "--test-threads=1"])
+        .env(EXIT_CHILD, "1")
+        .output()
+        .unwrap()
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
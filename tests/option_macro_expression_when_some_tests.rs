@@ -1,3 +1,5 @@
+#![cfg(feature = "option")]
+
 use test_toolbox::capture;
 
 use macrofied_toolbox::option;
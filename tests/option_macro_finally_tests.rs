@@ -0,0 +1,98 @@
+#![cfg(feature = "option")]
+#![allow(unused_assignments)]
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_some_option_should_eval_some_then_finally() {
+    let expected = vec!["some", "finally"];
+    let mut actual = Vec::new();
+
+    option! {
+        @when    foo_some()
+        @some    { actual.push("some"); }
+        @none    { actual.push("none"); }
+        @finally { actual.push("finally"); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_none_option_should_eval_none_then_finally() {
+    let expected = vec!["none", "finally"];
+    let mut actual = Vec::new();
+
+    option! {
+        @when    foo_none()
+        @some    { actual.push("some"); }
+        @none    { actual.push("none"); }
+        @finally { actual.push("finally"); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_tried_none_option_should_eval_none_then_finally_and_return_none() {
+    let mut actual = Vec::new();
+    let result = test_try(&mut actual);
+
+    assert_eq!(None, result);
+    assert_eq!(vec!["none", "finally"], actual);
+
+    fn test_try(actual: &mut Vec<&'static str>) -> Option<usize> {
+        option! {
+            @when    foo_none()?
+            @none    { actual.push("none"); }
+            @finally { actual.push("finally"); }
+        }
+
+        actual.push("not reached");
+
+        Some(0)
+    }
+}
+
+#[test]
+fn when_tried_none_option_with_only_some_should_eval_finally_and_return_none() {
+    let mut actual = Vec::new();
+    let result = test_try(&mut actual);
+
+    assert_eq!(None, result);
+    assert_eq!(vec!["finally"], actual);
+
+    fn test_try(actual: &mut Vec<&'static str>) -> Option<usize> {
+        option! {
+            @when    foo_none()?
+            @some    { actual.push("some"); }
+            @finally { actual.push("finally"); }
+        }
+
+        Some(0)
+    }
+}
+
+#[test]
+fn when_some_option_expression_should_eval_finally_and_return_value() {
+    let expected = 42;
+    let mut finalized = false;
+
+    let actual = option! {
+        @some    foo_some()
+        @none    0
+        @finally finalized = true
+    };
+
+    assert_eq!(expected, actual);
+    assert!(finalized);
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
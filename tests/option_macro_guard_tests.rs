@@ -0,0 +1,105 @@
+#![cfg(feature = "option")]
+#![allow(unused_assignments)]
+
+use test_toolbox::capture;
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_some_option_passing_guard_should_output_some() {
+    let expected_stdout = "positive: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when      foo_some(42)
+            @some      (n) if n > 0 => "positive: {}", n
+            @otherwise "not positive: {some}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_some_option_failing_guard_should_output_otherwise() {
+    let expected_stdout = "not positive: -42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when      foo_some(-42)
+            @some      (n) if n > 0 => "positive: {}", n
+            @otherwise "not positive: {some}"
+            @none      "this will not output"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_none_tried_option_with_guard_should_return_none() {
+    let expected = None;
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Option<isize> {
+        let mut actual = 0;
+
+        option! {
+            @when      foo_none()?
+            @some      (n) if n > 0 => { actual = n; }
+            @otherwise { actual = -1; }
+        }
+
+        Some(actual)
+    }
+}
+
+#[test]
+fn when_some_tried_option_failing_guard_should_eval_otherwise() {
+    let expected = Some(-1);
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Option<isize> {
+        let mut actual = 0;
+
+        option! {
+            @when      foo_some(-42)?
+            @some      (n) if n > 0 => { actual = n; }
+            @otherwise { actual = -1; }
+        }
+
+        Some(actual)
+    }
+}
+
+#[test]
+fn when_some_tried_option_passing_guard_should_eval_some() {
+    let expected = Some(42);
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Option<isize> {
+        let mut actual = 0;
+
+        option! {
+            @when      foo_some(42)?
+            @some      (n) if n > 0 => { actual = n; }
+            @otherwise { actual = -1; }
+        }
+
+        Some(actual)
+    }
+}
+
+fn foo_some(value: isize) -> Option<isize> {
+    Some(value)
+}
+
+fn foo_none() -> Option<isize> {
+    None
+}
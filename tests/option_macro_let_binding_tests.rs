@@ -0,0 +1,77 @@
+#![cfg(feature = "option")]
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_some_option_should_bind_value() {
+    assert!(test_let());
+
+    fn test_let() -> bool {
+        option! {
+            let value = @when foo_some()
+            @none   "foo is none"
+            @return false
+        }
+
+        value == 42
+    }
+}
+
+#[test]
+fn when_none_option_should_eval_none_then_return() {
+    let mut nones = 0;
+
+    assert!(!test_let(&mut nones));
+    assert_eq!(1, nones);
+
+    fn test_let(nones: &mut usize) -> bool {
+        option! {
+            let value = @when foo_none()
+            @none   { *nones += 1; }
+            @return false
+        }
+
+        value == 42
+    }
+}
+
+#[test]
+fn when_tried_none_option_should_return_none() {
+    assert_eq!(None, test_let(None));
+    assert_eq!(Some(43), test_let(Some(42)));
+
+    fn test_let(input: Option<usize>) -> Option<usize> {
+        option! {
+            let mut value = @when input?
+        }
+
+        value += 1;
+
+        Some(value)
+    }
+}
+
+#[test]
+fn when_none_option_should_break() {
+    let mut actual = Vec::new();
+
+    for input in [Some(1), None, Some(3)] {
+        option! {
+            let value: usize = @when input
+            @break
+        }
+
+        actual.push(value);
+    }
+
+    assert_eq!(vec![1], actual);
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
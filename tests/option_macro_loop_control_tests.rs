@@ -0,0 +1,90 @@
+#![cfg(feature = "option")]
+
+use macrofied_toolbox::option;
+
+const INPUTS: [Option<usize>; 4] = [Some(1), None, Some(3), Some(4)];
+
+#[test]
+fn when_none_option_should_eval_none_then_continue() {
+    let mut actual = Vec::new();
+    let mut nones = 0;
+
+    for input in INPUTS {
+        option! {
+            @when     input
+            @some     (value) => { actual.push(value); }
+            @none     { nones += 1; }
+            @continue
+        }
+
+        actual.push(0);
+    }
+
+    assert_eq!(vec![1, 0, 3, 0, 4, 0], actual);
+    assert_eq!(1, nones);
+}
+
+#[test]
+fn when_none_option_should_eval_none_then_break() {
+    let mut actual = Vec::new();
+    let mut nones = 0;
+
+    for input in INPUTS {
+        option! {
+            @when  input
+            @some  (value) => { actual.push(value); }
+            @none  { nones += 1; }
+            @break
+        }
+    }
+
+    assert_eq!(vec![1], actual);
+    assert_eq!(1, nones);
+}
+
+#[test]
+fn when_none_option_should_break_labeled_loop() {
+    let mut actual = Vec::new();
+
+    'outer: loop {
+        for input in INPUTS {
+            option! {
+                @when  input
+                @some  (value) => { actual.push(value); }
+                @break 'outer
+            }
+        }
+    }
+
+    assert_eq!(vec![1], actual);
+}
+
+#[test]
+fn when_none_option_with_only_escape_should_continue() {
+    let mut count = 0;
+
+    for input in INPUTS {
+        option! {
+            @when     input
+            @continue
+        }
+
+        count += 1;
+    }
+
+    assert_eq!(3, count);
+}
+
+#[test]
+fn when_none_option_expression_should_continue() {
+    let mut actual = 0;
+
+    for input in INPUTS {
+        actual += option! {
+            @some     input
+            @continue
+        };
+    }
+
+    assert_eq!(8, actual);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "option")]
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_some_option_expression_should_map_value() {
+    let expected = String::from("42");
+
+    let actual = option! {
+        @some foo_some()
+        @map  |value| value.to_string()
+        @none String::new()
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_none_option_expression_should_not_map_value() {
+    let expected = String::from("none");
+
+    let actual = option! {
+        @some foo_none()
+        @map  |value| value.to_string()
+        @none String::from("none")
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_some_option_should_bind_mapped_value() {
+    option! {
+        let actual = @when foo_some()
+        @map   |value| value * 2
+        @panic "foo is none"
+    }
+
+    assert_eq!(84, actual);
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "option")]
+
 use test_toolbox::capture;
 
 use macrofied_toolbox::option;
@@ -325,6 +327,62 @@ fn when_message_with_an_unsafe_expression_should_output() {
     }
 }
 
+#[test]
+fn when_message_with_named_arguments_should_output() {
+    let expected_stdout = "some: 42 - 21\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_ok()
+            @some (baz) => "some: {doubled} - {baz}", doubled = baz * 2, baz = baz
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_message_with_a_raw_string_should_output() {
+    let expected_stdout = "some: \"42\"\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_ok()
+            @some (baz) => r#"some: "{}""#, baz * 2
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_message_with_a_trailing_comma_should_output() {
+    let expected_stdout = "some: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_ok()
+            @some (baz) => "some: {}", baz * 2,
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_message_with_width_and_precision_arguments_should_output() {
+    let expected_stdout = "some:   42.0|42.00\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_ok()
+            @some (baz) => "some: {:>width$.1}|{:.*}", baz as f64 * 2.0, 2, 42.0, width = 6
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
 fn foo_ok() -> Option<usize> {
     Some(21)
 }
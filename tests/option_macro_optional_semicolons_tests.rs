@@ -1,3 +1,5 @@
+#![cfg(feature = "option")]
+
 use cfg_if::cfg_if;
 use test_toolbox::capture;
 #[cfg(feature = "option-debug")]
@@ -0,0 +1,66 @@
+#![cfg(feature = "option")]
+
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use macrofied_toolbox::option;
+
+#[test]
+#[should_panic(expected = "could not foo 42")]
+fn when_none_option_should_panic_with_message() {
+    let path = 42;
+
+    option! {
+        @when  foo_none()
+        @panic "could not foo {}", path
+    }
+}
+
+#[test]
+fn when_none_option_should_eval_none_then_panic() {
+    let nothing = Cell::new(false);
+    let path = 42;
+
+    let actual = catch_unwind(AssertUnwindSafe(|| {
+        option! {
+            @when  foo_none()
+            @none  { nothing.set(true); }
+            @panic "could not foo {}", path
+        }
+    }));
+
+    let message = actual.unwrap_err().downcast::<String>().unwrap();
+
+    assert!(nothing.get());
+    assert_eq!("could not foo 42", *message);
+}
+
+#[test]
+fn when_some_option_expression_should_not_panic() {
+    let expected = 42;
+
+    let actual = option! {
+        @some  foo_some()
+        @panic "could not foo"
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[should_panic(expected = "could not foo")]
+fn when_none_option_expression_should_panic() {
+    let _actual = option! {
+        @some  foo_none()
+        @panic "could not foo"
+    };
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
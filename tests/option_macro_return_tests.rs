@@ -0,0 +1,80 @@
+#![cfg(feature = "option")]
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_none_option_should_eval_none_then_return_value() {
+    let mut nones = 0;
+
+    assert!(!test_return(&mut nones));
+    assert_eq!(1, nones);
+
+    fn test_return(nones: &mut usize) -> bool {
+        option! {
+            @when   foo_none()
+            @none   { *nones += 1; }
+            @return false
+        }
+
+        true
+    }
+}
+
+#[test]
+fn when_some_option_should_not_return() {
+    assert_eq!(vec![42], test_return());
+
+    fn test_return() -> Vec<usize> {
+        let mut actual = Vec::new();
+
+        option! {
+            @when   foo_some()
+            @some   (value) => { actual.push(value); }
+            @return Vec::new()
+        }
+
+        actual
+    }
+}
+
+#[test]
+fn when_none_option_should_return_from_unit_function() {
+    let mut actual = Vec::new();
+
+    test_return(&mut actual);
+
+    assert_eq!(vec!["none"], actual);
+
+    fn test_return(actual: &mut Vec<&'static str>) {
+        option! {
+            @when   foo_none()
+            @none   { actual.push("none"); }
+            @return;
+        }
+
+        actual.push("not reached");
+    }
+}
+
+#[test]
+fn when_none_option_expression_should_return_value() {
+    assert_eq!(0, test_return());
+
+    fn test_return() -> usize {
+        let value = option! {
+            @some   foo_none()
+            @return 0
+        };
+
+        value + 1
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "option")]
+
 use cfg_if::cfg_if;
 use test_toolbox::capture;
 #[cfg(feature = "option-debug")]
@@ -344,10 +346,111 @@ fn when_mut_reference_option_should_evaluate_some() {
     fn foo_some_ref(value: &mut usize) -> Option<&mut usize> { Some(value) }
 }
 
+#[test]
+fn when_none_option_should_output_raw_string_message() {
+    let expected_stderr = "none: \"example\" message\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        option! {
+            @when foo_none()
+            @none r#"none: "{}" message"#, "example"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_some_option_should_output_inline_captured_some() {
+    let expected_stdout = "some:   42|42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_some()
+            @some "some: {some:>4}|{some}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_some_option_should_output_inline_captured_custom_some() {
+    let expected_stdout = "some: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_some()
+            @some (foo) => "some: {foo:?}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_some_option_should_output_destructured_struct_some() {
+    let expected_stdout = "some: localhost:8080\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        option! {
+            @when foo_config_some()
+            @some (Config { host, port, .. }) => "some: {host}:{port}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_some_option_should_eval_destructured_tuple_struct_some() {
+    let expected = 42;
+    let mut actual = 0;
+
+    option! {
+        @when foo_wrapped_some()
+        @some (Wrapped(value)) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_none_option_with_sections_in_any_order_should_output_none() {
+    let expected_stderr = "none: foo failed!\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        option! {
+            @none "none: foo failed!"
+            @some "this will not output: {}", some
+            @when foo_none()
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
 fn foo_some() -> Option<usize> {
     Some(42)
 }
 
 fn foo_none() -> Option<usize> {
     None
-}
\ No newline at end of file
+}
+
+struct Config {
+    host: &'static str,
+    port: u16,
+    #[allow(dead_code)]
+    secure: bool,
+}
+
+struct Wrapped(usize);
+
+fn foo_config_some() -> Option<Config> {
+    Some(Config { host: "localhost", port: 8080, secure: false })
+}
+
+fn foo_wrapped_some() -> Option<Wrapped> {
+    Some(Wrapped(42))
+}
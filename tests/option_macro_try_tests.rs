@@ -1,3 +1,4 @@
+#![cfg(feature = "option")]
 #![allow(unused_assignments)]
 
 use cfg_if::cfg_if;
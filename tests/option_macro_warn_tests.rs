@@ -0,0 +1,113 @@
+#![cfg(feature = "option")]
+
+use cfg_if::cfg_if;
+use test_toolbox::capture;
+#[cfg(feature = "option-debug")]
+use test_toolbox::expect;
+
+use macrofied_toolbox::option;
+
+#[test]
+fn when_none_option_with_warn_should_output_warn() {
+    cfg_if! {
+        if #[cfg(feature = "option-warn")] {
+            let expected_stderr = "warning: foo is missing\n";
+        } else {
+            let expected_stderr = "";
+        }
+    }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        option! {
+            @when  foo_none()
+            @warn  "foo is missing"
+        }
+    };
+
+    assert_eq!("", actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_some_option_with_warn_should_not_output_warn() {
+    let (actual_stdout, actual_stderr) = capture! {
+        option! {
+            @when  foo_some()
+            @some  "some: {}", some
+            @warn  "foo is missing"
+        }
+    };
+
+    assert_eq!("some: 42\n", actual_stdout);
+    assert_eq!("", actual_stderr);
+}
+
+#[test]
+fn when_none_option_with_debug_warn_and_none_should_output_all() {
+    cfg_if! {
+        if #[cfg(feature = "option-debug")] {
+            expect! { expected_stdout = "", "dbg: foo is missing\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    cfg_if! {
+        if #[cfg(feature = "option-warn")] {
+            let expected_stderr = "warning: foo is missing\nfoo is missing\n";
+        } else {
+            let expected_stderr = "foo is missing\n";
+        }
+    }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        option! {
+            @when  foo_none()
+            @some  "this will not output: {}", some
+            @debug "dbg: foo is missing"
+            @warn  "foo is missing"
+            @none  "foo is missing"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_tried_none_option_with_warn_should_output_warn_and_return_none() {
+    let expected = None;
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Option<usize> {
+        cfg_if! {
+            if #[cfg(feature = "option-warn")] {
+                let expected_stderr = "warning: foo is missing\n";
+            } else {
+                let expected_stderr = "";
+            }
+        }
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            option! {
+                @when  foo_none()?
+                @warn  "foo is missing"
+            }
+        };
+
+        assert_eq!(expected_stderr, actual_stderr);
+
+        Some(0)
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "option")]
+
+use test_toolbox::capture;
+
+use macrofied_toolbox::option;
+
+#[test]
+#[allow(clippy::redundant_closure, clippy::redundant_closure_call)]
+fn when_closure_call_should_eval_some() {
+    let expected = 42;
+    let mut actual = 0;
+
+    option! {
+        @when  (|| foo_some())()
+        @some  (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[allow(clippy::never_loop)]
+fn when_loop_should_eval_some() {
+    let expected = 42;
+    let mut actual = 0;
+
+    option! {
+        @when  loop { break foo_some(); }
+        @some  (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_paren_should_eval_none() {
+    let expected = "none";
+    let mut actual = "";
+
+    option! {
+        @when  (foo_none());
+        @none  { actual = "none"; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_paren_with_try_should_eval_inner_option() {
+    let expected = Some("none");
+    let actual = test_paren();
+
+    assert_eq!(expected, actual);
+
+    fn test_paren() -> Option<&'static str> {
+        let mut actual = "";
+
+        option! {
+            @when  (nested()?);
+            @none  { actual = "none"; }
+        }
+
+        Some(actual)
+    }
+}
+
+#[test]
+fn when_tuple_field_should_eval_none() {
+    let expected = "none";
+    let mut actual = "";
+    let pair = (foo_some(), foo_none());
+
+    option! {
+        @when  pair.1;
+        @none  { actual = "none"; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_unary_should_eval_some() {
+    let expected = 42;
+    let mut actual = 0;
+    let option = &foo_some();
+
+    option! {
+        @when  *option;
+        @some  (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_unsafe_block_should_eval_some() {
+    let expected = 42;
+    let mut actual = 0;
+
+    option! {
+        @when  unsafe { foo_unsafe() };
+        @some  (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_deref_with_only_none_should_eval_none() {
+    let option = foo_none();
+    let r = &option;
+
+    let (_, actual_stderr) = capture! {
+        option! {
+            @when *r;
+            @none "failed"
+        }
+    };
+
+    assert_eq!("failed\n", actual_stderr);
+}
+
+#[test]
+fn when_paren_deref_with_only_none_should_eval_none() {
+    let option = foo_none();
+    let r = &option;
+
+    let (_, actual_stderr) = capture! {
+        option! {
+            @when (*r);
+            @none "failed"
+        }
+    };
+
+    assert_eq!("failed\n", actual_stderr);
+}
+
+#[test]
+fn when_deref_with_only_some_message_should_eval_some() {
+    let option = foo_some();
+    let r = &option;
+
+    let (actual_stdout, _) = capture! {
+        option! {
+            @when *r;
+            @some "succeeded"
+        }
+    };
+
+    assert_eq!("succeeded\n", actual_stdout);
+}
+
+#[test]
+fn when_paren_deref_with_only_some_message_should_eval_some() {
+    let option = foo_some();
+    let r = &option;
+
+    let (actual_stdout, _) = capture! {
+        option! {
+            @when (*r);
+            @some "succeeded"
+        }
+    };
+
+    assert_eq!("succeeded\n", actual_stdout);
+}
+
+#[test]
+fn when_paren_struct_literal_should_eval_some() {
+    let expected = 42;
+    let mut actual = 0;
+
+    option! {
+        @when  (Wrapper { option: foo_some() }.option)
+        @some  (value) => { actual = value; }
+        @none  "missing"
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_paren_struct_literal_option_expression_should_map_value() {
+    let expected = String::from("42");
+
+    let actual = option! {
+        @some  (Wrapper { option: foo_some() }.option)
+        @map   |value| value.to_string()
+        @none  String::new()
+    };
+
+    assert_eq!(expected, actual);
+}
+
+struct Wrapper {
+    option: Option<usize>,
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_some() -> Option<usize> {
+    Some(42)
+}
+
+const fn foo_none() -> Option<usize> {
+    None
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const unsafe fn foo_unsafe() -> Option<usize> {
+    Some(42)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn nested() -> Option<Option<usize>> {
+    Some(None)
+}
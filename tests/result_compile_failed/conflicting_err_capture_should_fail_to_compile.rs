@@ -0,0 +1,13 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when  foo()
+        @debug (e) => "dbg: {:?}", e
+        @error (reason) => "err: {}", reason
+    }
+}
+
+fn foo() -> Result<(), &'static str> {
+    Err("foo failed!")
+}
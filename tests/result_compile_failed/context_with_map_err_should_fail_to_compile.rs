@@ -0,0 +1,19 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    inner().unwrap();
+
+    fn inner() -> Result<usize, String> {
+        result! {
+            @when    foo()?;
+            @map_err String::from
+            @context "reading foo"
+        }
+
+        Ok(0)
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
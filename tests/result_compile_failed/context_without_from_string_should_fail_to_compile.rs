@@ -0,0 +1,29 @@
+use std::fmt::{self, Display, Formatter};
+
+use macrofied_toolbox::result;
+
+#[derive(Debug)]
+struct FooError;
+
+impl Display for FooError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "foo failed")
+    }
+}
+
+fn main() {
+    let _ = bar();
+}
+
+fn bar() -> Result<usize, FooError> {
+    result! {
+        @when    foo()?
+        @context "reading foo"
+    }
+
+    Ok(0)
+}
+
+fn foo() -> Result<usize, FooError> {
+    Err(FooError)
+}
@@ -0,0 +1,14 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when  foo();
+        @error (e) if e.is_empty() => "empty";
+        @error "first error";
+        @error "second error";
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
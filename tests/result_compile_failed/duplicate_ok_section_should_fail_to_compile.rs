@@ -0,0 +1,14 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when foo();
+        @ok   (0) => "zero";
+        @ok   "first ok";
+        @ok   "second ok";
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
@@ -0,0 +1,13 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when  foo();
+        @debug "first debug";
+        @debug "second debug";
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
@@ -0,0 +1,16 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    for input in ["1", "two"] {
+        let _ = foo(input);
+    }
+}
+
+fn foo(input: &str) -> Result<usize, std::num::ParseIntError> {
+    result! {
+        @when     input.parse::<usize>()?
+        @continue
+    }
+
+    Ok(0)
+}
@@ -0,0 +1,13 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when  foo();
+        @ok    (n) if n > 0 => "positive: {}", n;
+        @error "failed: {}", err;
+    }
+}
+
+fn foo() -> Result<isize, &'static str> {
+    Ok(42)
+}
@@ -0,0 +1,12 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when foo();
+        @ok   "{} and {} will not compile", ok;
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
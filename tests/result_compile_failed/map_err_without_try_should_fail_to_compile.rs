@@ -0,0 +1,13 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when    foo();
+        @error   "foo failed";
+        @map_err |err| err.len()
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
@@ -0,0 +1,9 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when  "1".parse::<usize>()
+        @map   |value| value + 1
+        @error "err: {}", err
+    }
+}
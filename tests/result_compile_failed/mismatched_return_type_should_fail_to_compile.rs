@@ -1,6 +1,9 @@
 use macrofied_toolbox::result;
 
-fn main() -> Result<(), String> {
+#[derive(Debug)]
+struct FooError;
+
+fn main() -> Result<(), FooError> {
     result! {
         @when foo()?;
         @ok   "will not compile";
@@ -11,4 +14,4 @@ fn main() -> Result<(), String> {
 
 fn foo() -> Result<usize, &'static str> {
     Ok(42)
-}
\ No newline at end of file
+}
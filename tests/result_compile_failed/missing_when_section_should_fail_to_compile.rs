@@ -0,0 +1,8 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @debug "foo failed";
+        @error   "foo failed";
+    }
+}
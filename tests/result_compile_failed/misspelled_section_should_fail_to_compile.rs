@@ -0,0 +1,12 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when   foo();
+        @errror "foo failed";
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
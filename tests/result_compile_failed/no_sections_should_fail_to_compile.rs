@@ -0,0 +1,11 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when  foo();
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
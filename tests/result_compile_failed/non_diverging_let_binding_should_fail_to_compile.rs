@@ -0,0 +1,8 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        let _value = @when "1".parse::<usize>()
+        @error "err: {}", err
+    }
+}
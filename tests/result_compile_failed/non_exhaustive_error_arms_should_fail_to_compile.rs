@@ -0,0 +1,17 @@
+use macrofied_toolbox::result;
+
+enum FooError {
+    NotFound,
+    Denied,
+}
+
+fn main() {
+    result! {
+        @when  foo();
+        @error (FooError::NotFound) => "not found";
+    }
+}
+
+fn foo() -> Result<usize, FooError> {
+    Err(FooError::Denied)
+}
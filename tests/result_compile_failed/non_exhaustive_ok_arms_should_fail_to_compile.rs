@@ -0,0 +1,13 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when foo();
+        @ok   (Some(0)) => "zero";
+        @ok   (None) => "nothing";
+    }
+}
+
+fn foo() -> Result<Option<usize>, &'static str> {
+    Ok(Some(42))
+}
@@ -0,0 +1,10 @@
+use macrofied_toolbox::result;
+
+fn main() -> Result<(), std::num::ParseIntError> {
+    result! {
+        let _value = @when "1".parse::<usize>()?
+        @ok    "got {}", ok
+    }
+
+    Ok(())
+}
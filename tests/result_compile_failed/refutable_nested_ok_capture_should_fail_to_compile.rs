@@ -0,0 +1,12 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when foo();
+        @ok   ((0, value)) => "zero: {}", value;
+    }
+}
+
+fn foo() -> Result<(usize, usize), &'static str> {
+    Ok((0, 42))
+}
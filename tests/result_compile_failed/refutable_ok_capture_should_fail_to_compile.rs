@@ -0,0 +1,12 @@
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when foo();
+        @ok   (42) => "the answer";
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
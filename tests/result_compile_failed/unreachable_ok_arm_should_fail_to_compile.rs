@@ -0,0 +1,15 @@
+#![deny(unreachable_patterns)]
+
+use macrofied_toolbox::result;
+
+fn main() {
+    result! {
+        @when foo();
+        @ok   (value) => "value: {value}";
+        @ok   (other) => "other: {other}";
+    }
+}
+
+fn foo() -> Result<usize, &'static str> {
+    Ok(42)
+}
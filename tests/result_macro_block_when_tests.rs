@@ -0,0 +1,156 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_block_with_try_should_propagate_err() {
+    let expected = Err("foo failed!");
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> TestResult {
+        let mut actual = "";
+
+        result! {
+            @when  {
+                let value = foo_err()?;
+
+                bar(value)
+            }
+            @ok    (value) => { actual = "ok"; assert_eq!(42, value); }
+            @error { actual = err; }
+        }
+
+        assert_eq!("this will not be reached", actual);
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_block_with_try_should_eval_ok() {
+    let expected = Ok(42);
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> TestResult {
+        let mut actual = 0;
+
+        result! {
+            @when  {
+                let value = foo_ok()?;
+
+                bar(value)
+            }
+            @ok    (value) => { actual = value; }
+        }
+
+        Ok(actual)
+    }
+}
+
+#[test]
+fn when_try_block_should_route_err_to_error() {
+    let expected = "foo failed!";
+    let mut actual = "";
+
+    result! {
+        @when  try {
+            let value = foo_err()?;
+
+            bar(value)?
+        }
+        @ok    "this will not output: {:?}", ok
+        @error { actual = err; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_try_block_should_eval_ok() {
+    let expected = 42;
+    let mut actual = 0;
+
+    result! {
+        @when  try {
+            let value = foo_ok()?;
+
+            bar(value)?
+        }
+        @ok    (value) => { actual = value; }
+        @error "this will not output: {:?}", err
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_tried_try_block_should_eval_err_and_return() {
+    let expected = Err("foo failed!");
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> TestResult {
+        let expected = "foo failed!";
+        let mut actual = "";
+
+        result! {
+            @when  try {
+                let value = foo_err()?;
+
+                bar(value)?
+            }?
+            @error { actual = err; }
+        }
+
+        assert_eq!(expected, actual);
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_try_block_with_return_should_return_from_function() {
+    let expected = Ok(42);
+    let actual = test_block();
+
+    assert_eq!(expected, actual);
+
+    fn test_block() -> TestResult {
+        result! {
+            @when  try {
+                let value = foo_ok()?;
+
+                if value > 0 {
+                    return bar(value);
+                }
+
+                value
+            }
+            @ok    "this will not output: {}", ok
+            @error "this will not output: {}", err
+        }
+
+        Ok(0)
+    }
+}
+
+fn foo_ok() -> TestResult {
+    Ok(21)
+}
+
+fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn bar(value: usize) -> TestResult {
+    Ok(value * 2)
+}
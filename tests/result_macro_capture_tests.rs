@@ -0,0 +1,179 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use cfg_if::cfg_if;
+use test_toolbox::capture;
+#[cfg(feature = "result-debug")]
+use test_toolbox::expect;
+
+use macrofied_toolbox::result;
+
+#[test]
+fn when_err_result_should_output_custom_captured_err() {
+    let expected_stderr = "err: foo failed!\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @error (e) => "err: {}", e
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_output_inline_custom_captured_err() {
+    let expected_stderr = "err: foo failed!\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @error (reason) => "err: {reason}"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_eval_custom_captured_err() {
+    let expected = "foo failed!";
+    let mut actual = "";
+
+    result! {
+        @when  foo_err()
+        @error (e) => { actual = e; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_should_eval_mutable_custom_captured_err() {
+    let expected = "foo failed!!";
+    let mut actual = String::new();
+
+    result! {
+        @when  foo_string_err()
+        @error (mut e) => { e.push('!'); actual = e; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_should_not_shadow_local_err() {
+    let expected = "local err: foo failed!";
+    let err = "local err";
+    let mut actual = String::new();
+
+    result! {
+        @when  foo_err()
+        @error (e) => { actual = format!("{}: {}", err, e); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_should_output_dbg_custom_captured_err_in_both_sections() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! { expected_stdout = "", "dbg: \"foo failed!\"\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    let expected_stderr = "err: foo failed!\n";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @debug (io_err) => "dbg: {:?}", io_err
+            @error "err: {}", io_err
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_tried_result_should_output_and_return_custom_captured_err() {
+    let expected = Err("foo failed!");
+    let expected_stderr = "err: foo failed!\n";
+
+    let (actual, actual_stderr) = {
+        let mut actual = Ok(());
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            actual = test_try()
+        };
+
+        (actual, actual_stderr)
+    };
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected_stderr, actual_stderr);
+
+    fn test_try() -> Result<(), &'static str> {
+        result! {
+            @when  foo_err()?
+            @error (e) => "err: {e}"
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn when_err_result_should_output_inline_captured_err_in_expression() {
+    let expected_stderr = "failed: foo failed!\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @error { eprintln!("failed: {err}"); }
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_tried_result_should_output_inline_captured_err_in_expression_and_return_err() {
+    let expected = Err("foo failed!");
+    let expected_stderr = "failed: foo failed!\n";
+
+    let (actual, actual_stderr) = {
+        let mut actual = Ok(());
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            actual = test_try()
+        };
+
+        (actual, actual_stderr)
+    };
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected_stderr, actual_stderr);
+
+    fn test_try() -> Result<(), &'static str> {
+        result! {
+            @when  foo_err()?
+            @error { eprintln!("failed: {err}"); }
+        }
+
+        Ok(())
+    }
+}
+
+fn foo_err() -> Result<(), &'static str> {
+    Err("foo failed!")
+}
+
+fn foo_string_err() -> Result<(), String> {
+    Err(String::from("foo failed!"))
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use std::io;
+
+use cfg_if::cfg_if;
+use test_toolbox::capture;
+#[cfg(feature = "result-debug")]
+use test_toolbox::expect;
+
+use macrofied_toolbox::result;
+
+#[derive(Debug, PartialEq)]
+enum FooError {
+    NotFound(&'static str),
+    Denied,
+}
+
+#[test]
+fn when_err_result_should_output_matching_error_arm_only() {
+    let expected_stderr = "missing foo.txt\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err(FooError::NotFound("foo.txt"))
+            @error (FooError::NotFound(path)) => "missing {}", path
+            @error (other) => "other: {other:?}"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_output_catch_all_error_arm() {
+    let expected_stderr = "other: Denied\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err(FooError::Denied)
+            @error (FooError::NotFound(path)) => "missing {}", path
+            @error (other) => "other: {other:?}"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_output_guarded_error_arm() {
+    let expected_stderr = "not found: missing\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_io_err(io::ErrorKind::NotFound)
+            @error (e) if e.kind() == io::ErrorKind::NotFound => "not found: {e}"
+            @error "other: {err}"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_skip_failed_guard_error_arm() {
+    let expected_stderr = "other: missing\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_io_err(io::ErrorKind::PermissionDenied)
+            @error (e) if e.kind() == io::ErrorKind::NotFound => "not found: {e}"
+            @error "other: {err}"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_eval_matching_error_arm() {
+    let expected = "denied";
+    let mut actual = "";
+
+    result! {
+        @when  foo_err(FooError::Denied)
+        @error (FooError::NotFound(_)) => { actual = "not found"; }
+        @error (FooError::Denied) => { actual = "denied"; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_with_error_arms_should_eval_matching_error_arm_expression() {
+    let expected = 2;
+
+    let actual = result! {
+        @when  foo_err(FooError::Denied)
+        @ok    (value) => value
+        @error (FooError::NotFound(_)) => 1
+        @error (FooError::Denied) => 2
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_with_error_arms_should_output_dbg_and_err() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! { expected_stdout = "", "dbg: NotFound(\"foo.txt\")\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    let expected_stderr = "missing foo.txt\n";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err(FooError::NotFound("foo.txt"))
+            @ok    "this will not output: {ok}"
+            @debug "dbg: {err:?}"
+            @error (FooError::NotFound(path)) => "missing {}", path
+            @error (FooError::Denied) => "denied"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_tried_result_with_error_arms_should_output_and_return_err() {
+    let expected = Err(FooError::NotFound("foo.txt"));
+    let expected_stderr = "missing foo.txt\n";
+
+    let (actual, actual_stderr) = {
+        let mut actual = Ok(0);
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            actual = test_try()
+        };
+
+        (actual, actual_stderr)
+    };
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected_stderr, actual_stderr);
+
+    fn test_try() -> Result<usize, FooError> {
+        result! {
+            @when  foo_err(FooError::NotFound("foo.txt"))?
+            @error (FooError::NotFound(path)) => "missing {}", path
+            @error (FooError::Denied) => "denied"
+        }
+
+        Ok(0)
+    }
+}
+
+fn foo_err(error: FooError) -> Result<usize, FooError> {
+    Err(error)
+}
+
+fn foo_io_err(kind: io::ErrorKind) -> Result<usize, io::Error> {
+    Err(io::Error::new(kind, "missing"))
+}
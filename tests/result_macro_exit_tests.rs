@@ -0,0 +1,92 @@
+#![cfg(feature = "result")]
+
+use std::env;
+use std::process::{Command, Output};
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+const EXIT_CHILD: &str = "RESULT_MACRO_EXIT_CHILD";
+
+#[test]
+fn when_err_result_should_eval_error_then_exit() {
+    if env::var_os(EXIT_CHILD).is_some() {
+        result! {
+            @when  foo_err()
+            @error "err: {}", err
+            @exit  2
+        }
+
+        unreachable!();
+    }
+
+    let output = exit_child("when_err_result_should_eval_error_then_exit");
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("err: foo failed!\n"));
+}
+
+#[test]
+fn when_err_result_should_flush_stdout_then_exit() {
+    if env::var_os(EXIT_CHILD).is_some() {
+        print!("partial output");
+
+        result! {
+            @when foo_err()
+            @exit (1 + 2)
+        }
+
+        unreachable!();
+    }
+
+    let output = exit_child("when_err_result_should_flush_stdout_then_exit");
+
+    assert_eq!(Some(3), output.status.code());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("partial output"));
+}
+
+#[test]
+fn when_err_result_expression_should_exit() {
+    if env::var_os(EXIT_CHILD).is_some() {
+        let _value: usize = result! {
+            @ok   foo_err()
+            @exit 4
+        };
+
+        unreachable!();
+    }
+
+    let output = exit_child("when_err_result_expression_should_exit");
+
+    assert_eq!(Some(4), output.status.code());
+}
+
+#[test]
+fn when_ok_result_expression_should_not_exit() {
+    let expected = 42;
+
+    let actual: usize = result! {
+        @ok   foo_ok()
+        @exit 1
+    };
+
+    assert_eq!(expected, actual);
+}
+
+fn exit_child(test: &str) -> Output {
+    Command::new(env::current_exe().unwrap())
+        .args(["--exact", test, "--nocapture", "--test-threads=1"])
+        .env(EXIT_CHILD, "1")
+        .output()
+        .unwrap()
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
@@ -1,6 +1,8 @@
+#![cfg(feature = "result")]
+
 use cfg_if::cfg_if;
 use test_toolbox::capture;
-#[cfg(feature = "option-debug")]
+#[cfg(feature = "result-debug")]
 use test_toolbox::expect;
 
 use macrofied_toolbox::result;
@@ -1,3 +1,5 @@
+#![cfg(feature = "result")]
+
 use test_toolbox::capture;
 
 use macrofied_toolbox::result;
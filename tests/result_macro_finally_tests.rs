@@ -0,0 +1,117 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_ok_result_should_eval_ok_then_finally() {
+    let expected = vec!["ok", "finally"];
+    let mut actual = Vec::new();
+
+    result! {
+        @when    foo_ok()
+        @ok      { actual.push("ok"); }
+        @error   { actual.push("error"); }
+        @finally { actual.push("finally"); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_should_eval_error_then_finally() {
+    let expected = vec!["error", "finally"];
+    let mut actual = Vec::new();
+
+    result! {
+        @when    foo_err()
+        @ok      { actual.push("ok"); }
+        @error   { actual.push("error"); }
+        @finally { actual.push("finally"); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_with_only_ok_should_eval_finally() {
+    let expected = vec!["finally"];
+    let mut actual = Vec::new();
+
+    result! {
+        @finally actual.push("finally");
+        @when    foo_err()
+        @ok      { actual.push("ok"); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_tried_err_result_should_eval_error_then_finally_and_return_err() {
+    let expected = Err("foo failed!");
+    let mut actual = Vec::new();
+    let result = test_try(&mut actual);
+
+    assert_eq!(expected, result);
+    assert_eq!(vec!["error", "finally"], actual);
+
+    fn test_try(actual: &mut Vec<&'static str>) -> TestResult {
+        result! {
+            @when    foo_err()?
+            @ok      { actual.push("ok"); }
+            @error   { actual.push("error"); }
+            @finally { actual.push("finally"); }
+        }
+
+        actual.push("not reached");
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_result_with_only_ok_should_eval_finally_and_return_err() {
+    let expected = Err("foo failed!");
+    let mut actual = Vec::new();
+    let result = test_try(&mut actual);
+
+    assert_eq!(expected, result);
+    assert_eq!(vec!["finally"], actual);
+
+    fn test_try(actual: &mut Vec<&'static str>) -> TestResult {
+        result! {
+            @when    foo_err()?
+            @ok      { actual.push("ok"); }
+            @finally { actual.push("finally"); }
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_ok_result_expression_should_eval_finally_and_return_value() {
+    let expected = 42;
+    let mut finalized = false;
+
+    let actual = result! {
+        @ok      foo_ok()
+        @error   0
+        @finally finalized = true
+    };
+
+    assert_eq!(expected, actual);
+    assert!(finalized);
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use test_toolbox::capture;
+
+use macrofied_toolbox::result;
+
+#[test]
+fn when_ok_result_passing_guard_should_output_ok() {
+    let expected_stdout = "positive: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when      foo_ok(42)
+            @ok        (n) if n > 0 => "positive: {}", n
+            @otherwise "not positive: {ok}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_result_failing_guard_should_output_otherwise() {
+    let expected_stdout = "not positive: -42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when      foo_ok(-42)
+            @ok        (n) if n > 0 => "positive: {}", n
+            @otherwise "not positive: {ok}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_result_failing_guard_should_eval_custom_captured_otherwise() {
+    let expected = -42;
+    let mut actual = 0;
+
+    result! {
+        @when      foo_ok(-42)
+        @ok        (n) if n > 0 => { actual = n; }
+        @otherwise (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_ok_result_should_eval_first_passing_guarded_arm() {
+    let expected = "small";
+    let mut actual = "";
+
+    result! {
+        @when      foo_ok(7)
+        @ok        (n) if n > 100 => { actual = "large"; }
+        @ok        (n) if n > 0 => { actual = "small"; }
+        @otherwise { actual = "not positive"; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_tried_result_with_guard_should_output_and_return_err() {
+    let expected = Err("foo failed!");
+    let expected_stderr = "err: foo failed!\n";
+
+    let (actual, actual_stderr) = {
+        let mut actual = Ok(0);
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            actual = test_try()
+        };
+
+        (actual, actual_stderr)
+    };
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected_stderr, actual_stderr);
+
+    fn test_try() -> Result<isize, &'static str> {
+        let mut actual = 0;
+
+        result! {
+            @when      foo_err()?
+            @ok        (n) if n > 0 => { actual = n; }
+            @otherwise { actual = -1; }
+            @error     "err: {err}"
+        }
+
+        Ok(actual)
+    }
+}
+
+fn foo_ok(value: isize) -> Result<isize, &'static str> {
+    Ok(value)
+}
+
+fn foo_err() -> Result<isize, &'static str> {
+    Err("foo failed!")
+}
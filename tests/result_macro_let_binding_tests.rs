@@ -0,0 +1,106 @@
+#![cfg(feature = "result")]
+
+use std::num::ParseIntError;
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_ok_result_should_bind_value() {
+    assert_eq!(Some(43), test_let());
+
+    fn test_let() -> Option<usize> {
+        result! {
+            let value = @when foo_ok()
+            @error  "err: {}", err
+            @return None
+        }
+
+        Some(value + 1)
+    }
+}
+
+#[test]
+fn when_err_result_should_eval_error_then_return() {
+    let mut errors = Vec::new();
+
+    assert_eq!(None, test_let(&mut errors));
+    assert_eq!(vec!["foo failed!"], errors);
+
+    fn test_let(errors: &mut Vec<&'static str>) -> Option<usize> {
+        result! {
+            let value = @when foo_err()
+            @error  { errors.push(err); }
+            @return None
+        }
+
+        Some(value + 1)
+    }
+}
+
+#[test]
+fn when_ok_result_should_bind_mutable_typed_value() {
+    result! {
+        let mut actual: usize = @when foo_ok()
+        @panic "err: {}", err
+    }
+
+    actual += 1;
+
+    assert_eq!(43, actual);
+}
+
+#[test]
+fn when_ok_result_should_bind_pattern() {
+    result! {
+        let (left, right) = @when pair()
+        @panic "err: {}", err
+    }
+
+    assert_eq!((1, 2), (left, right));
+}
+
+#[test]
+fn when_tried_err_result_should_return_err() {
+    assert!(test_let("two").is_err());
+    assert_eq!(Ok(3), test_let("2"));
+
+    fn test_let(input: &str) -> Result<usize, ParseIntError> {
+        result! {
+            let value = @when input.parse::<usize>()?
+        }
+
+        Ok(value + 1)
+    }
+}
+
+#[test]
+fn when_err_result_should_continue() {
+    let mut actual = Vec::new();
+
+    for input in ["1", "two", "3"] {
+        result! {
+            let value = @when input.parse::<usize>()
+            @continue
+        }
+
+        actual.push(value);
+    }
+
+    assert_eq!(vec![1, 3], actual);
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn pair() -> Result<(usize, usize), &'static str> {
+    Ok((1, 2))
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "result")]
+
+use macrofied_toolbox::result;
+
+const INPUTS: [&str; 4] = ["1", "two", "3", "4"];
+
+#[test]
+fn when_err_result_should_eval_error_then_continue() {
+    let mut actual = Vec::new();
+    let mut errors = Vec::new();
+
+    for input in INPUTS {
+        result! {
+            @when     input.parse::<usize>()
+            @ok       (value) => { actual.push(value); }
+            @error    { errors.push(input); }
+            @continue
+        }
+
+        actual.push(0);
+    }
+
+    assert_eq!(vec![1, 0, 3, 0, 4, 0], actual);
+    assert_eq!(vec!["two"], errors);
+}
+
+#[test]
+fn when_err_result_should_eval_error_then_break() {
+    let mut actual = Vec::new();
+    let mut errors = Vec::new();
+
+    for input in INPUTS {
+        result! {
+            @when  input.parse::<usize>()
+            @ok    (value) => { actual.push(value); }
+            @error { errors.push(input); }
+            @break
+        }
+    }
+
+    assert_eq!(vec![1], actual);
+    assert_eq!(vec!["two"], errors);
+}
+
+#[test]
+fn when_err_result_should_continue_labeled_loop() {
+    let mut actual = Vec::new();
+
+    'outer: for row in [INPUTS, ["5", "6", "seven", "8"]] {
+        for input in row {
+            result! {
+                @when     input.parse::<usize>()
+                @ok       (value) => { actual.push(value); }
+                @continue 'outer
+            }
+        }
+    }
+
+    assert_eq!(vec![1, 5, 6], actual);
+}
+
+#[test]
+fn when_err_result_with_only_escape_should_break() {
+    let mut count = 0;
+
+    for input in INPUTS {
+        result! {
+            @when  input.parse::<usize>()
+            @break
+        }
+
+        count += 1;
+    }
+
+    assert_eq!(1, count);
+}
+
+#[test]
+fn when_err_result_should_eval_finally_then_continue() {
+    let mut actual = Vec::new();
+
+    for input in INPUTS {
+        result! {
+            @when     input.parse::<usize>()
+            @error    (err) => { actual.push(err.to_string()); }
+            @continue
+            @finally  { actual.push(String::from(input)); }
+        }
+    }
+
+    assert_eq!(vec!["1", "invalid digit found in string", "two", "3", "4"], actual);
+}
+
+#[test]
+fn when_err_result_expression_should_continue() {
+    let mut actual = 0;
+
+    for input in INPUTS {
+        actual += result! {
+            @ok       input.parse::<usize>()
+            @continue
+        };
+    }
+
+    assert_eq!(8, actual);
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use std::error::Error;
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[derive(Debug, PartialEq, Eq)]
+enum FooError {
+    Foo(String, &'static str),
+}
+
+#[test]
+fn when_tried_err_with_map_err_should_return_mapped_err() {
+    let expected = Err(FooError::Foo(String::from("foo.txt"), "foo failed!"));
+    let actual = test_map_err("foo.txt");
+
+    assert_eq!(expected, actual);
+
+    fn test_map_err(path: &str) -> Result<usize, FooError> {
+        result! {
+            @when    foo_err()?
+            @map_err |err| FooError::Foo(String::from(path), err)
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_with_map_err_should_eval_error_with_original_err() {
+    let expected = Err(FooError::Foo(String::from("foo.txt"), "foo failed!"));
+    let mut actual = "";
+    let result = test_map_err("foo.txt", &mut actual);
+
+    assert_eq!(expected, result);
+    assert_eq!("foo failed!", actual);
+
+    fn test_map_err(path: &str, actual: &mut &'static str) -> Result<usize, FooError> {
+        result! {
+            @when    foo_err()?
+            @error   { *actual = err; }
+            @map_err |err| FooError::Foo(String::from(path), err)
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_with_map_err_fn_should_return_mapped_err() {
+    let expected = Err(String::from("foo failed!"));
+    let actual = test_map_err();
+
+    assert_eq!(expected, actual);
+
+    fn test_map_err() -> Result<usize, String> {
+        result! {
+            @when    foo_err()?
+            @map_err String::from
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_ok_with_map_err_should_eval_ok() {
+    let expected = Ok(42);
+    let actual = test_map_err();
+
+    assert_eq!(expected, actual);
+
+    fn test_map_err() -> Result<usize, FooError> {
+        let mut actual = 0;
+
+        result! {
+            @when    foo_ok()?
+            @ok      (value) => { actual = value; }
+            @map_err |err| FooError::Foo(String::new(), err)
+        }
+
+        Ok(actual)
+    }
+}
+
+#[test]
+fn when_tried_err_with_context_should_return_err_with_context() {
+    let expected = "reading foo.txt: foo failed!";
+    let actual = test_context("foo.txt");
+
+    assert_eq!(expected, actual.unwrap_err().to_string());
+
+    fn test_context(path: &str) -> Result<usize, Box<dyn Error>> {
+        result! {
+            @when    foo_err()?
+            @context "reading {}", path
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_with_context_and_finally_should_eval_finally_and_return_err() {
+    let expected = Err(String::from("reading: foo failed!"));
+    let mut finalized = false;
+    let actual = test_context(&mut finalized);
+
+    assert_eq!(expected, actual);
+    assert!(finalized);
+
+    fn test_context(finalized: &mut bool) -> Result<usize, String> {
+        result! {
+            @when    foo_err()?
+            @context "reading"
+            @finally *finalized = true
+        }
+
+        Ok(0)
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
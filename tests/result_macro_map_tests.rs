@@ -0,0 +1,83 @@
+#![cfg(feature = "result")]
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_ok_result_expression_should_map_value() {
+    let expected = String::from("42");
+
+    let actual = result! {
+        @ok    foo_ok()
+        @map   |value| value.to_string()
+        @error String::new()
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_expression_should_not_map_value() {
+    let expected = String::from("foo failed!");
+
+    let actual = result! {
+        @ok    foo_err()
+        @map   |value| value.to_string()
+        @error (err) => String::from(err)
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_ok_result_expression_should_map_borrowed_value() {
+    let expected = "42";
+
+    let actual = result! {
+        @ok    padded()
+        @map   |value| value.trim().to_owned()
+        @error String::new()
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_ok_result_should_bind_mapped_value() {
+    result! {
+        let actual = @when foo_ok()
+        @map   |value| value * 2
+        @panic "err: {}", err
+    }
+
+    assert_eq!(84, actual);
+}
+
+#[test]
+fn when_tried_ok_result_expression_should_map_value() {
+    assert_eq!(Ok(43), test_try());
+
+    fn test_try() -> TestResult {
+        let value = result! {
+            @ok  foo_ok()?
+            @map |value| value + 1
+        };
+
+        Ok(value)
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn padded() -> Result<&'static str, &'static str> {
+    Ok("  42  ")
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use cfg_if::cfg_if;
+use test_toolbox::capture;
+#[cfg(feature = "result-debug")]
+use test_toolbox::expect;
+
+use macrofied_toolbox::result;
+
+#[derive(Debug)]
+enum Status {
+    Ready(usize),
+    Pending,
+    Closed,
+}
+
+#[test]
+fn when_ok_some_result_should_output_matching_arm_only() {
+    let expected_stdout = "found: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when foo_found(Some(42))
+            @ok   (Some(value)) => "found: {value}"
+            @ok   (None) => "not found"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_none_result_should_output_matching_arm_only() {
+    let expected_stdout = "not found\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when foo_found(None)
+            @ok   (Some(value)) => "found: {value}"
+            @ok   (None) => "not found"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_result_should_eval_matching_arm_only() {
+    let expected = 1;
+    let mut actual = 0;
+
+    result! {
+        @when foo_status(Status::Pending)
+        @ok   (Status::Ready(count)) => { actual = count + 10; }
+        @ok   (Status::Pending) => { actual = 1; }
+        @ok   (Status::Closed) => { actual = 2; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_ok_result_should_eval_destructured_arm() {
+    let expected = 42;
+    let mut actual = 0;
+
+    result! {
+        @when foo_status(Status::Ready(32))
+        @ok   (Status::Ready(count)) => { actual = count + 10; }
+        @ok   (Status::Pending | Status::Closed) => { actual = 1; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_ok_result_should_eval_catch_all_arm() {
+    let expected = "other: Closed";
+    let mut actual = String::new();
+
+    result! {
+        @when foo_status(Status::Closed)
+        @ok   (Status::Ready(count)) => { actual = format!("ready: {count}"); }
+        @ok   (status) => { actual = format!("other: {status:?}"); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_with_ok_arms_should_output_dbg_and_err() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! { expected_stdout = "", "dbg: \"foo failed!\"\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    let expected_stderr = "err: foo failed!\n";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @ok    (Some(value)) => "this will not output: {value}"
+            @ok    (None) => "this will not output"
+            @debug "dbg: {err:?}"
+            @error "err: {err}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_tried_result_with_ok_arms_should_return_err() {
+    let expected = Err("foo failed!");
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Result<usize, &'static str> {
+        let mut actual = 0;
+
+        result! {
+            @when foo_err()?
+            @ok   (Some(value)) => { actual = value; }
+            @ok   (None) => { actual = 1; }
+        }
+
+        Ok(actual)
+    }
+}
+
+#[test]
+fn when_ok_tried_result_with_ok_arms_should_eval_matching_arm() {
+    let expected = Ok(42);
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Result<usize, &'static str> {
+        let mut actual = 0;
+
+        result! {
+            @when foo_found(Some(42))?
+            @ok   (Some(value)) => { actual = value; }
+            @ok   (None) => { actual = 1; }
+        }
+
+        Ok(actual)
+    }
+}
+
+fn foo_found(found: Option<usize>) -> Result<Option<usize>, &'static str> {
+    Ok(found)
+}
+
+fn foo_status(status: Status) -> Result<Status, &'static str> {
+    Ok(status)
+}
+
+fn foo_err() -> Result<Option<usize>, &'static str> {
+    Err("foo failed!")
+}
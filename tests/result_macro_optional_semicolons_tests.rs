@@ -1,3 +1,5 @@
+#![cfg(feature = "result")]
+
 use cfg_if::cfg_if;
 use test_toolbox::capture;
 #[cfg(feature = "result-debug")]
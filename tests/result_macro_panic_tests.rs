@@ -0,0 +1,103 @@
+#![cfg(feature = "result")]
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe, catch_unwind};
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+#[should_panic(expected = "could not foo 42: foo failed!")]
+fn when_err_result_should_panic_with_message() {
+    let path = 42;
+
+    result! {
+        @when  foo_err()
+        @panic "could not foo {}: {}", path, err
+    }
+}
+
+#[test]
+#[should_panic(expected = "could not foo: foo failed!")]
+fn when_err_result_should_panic_with_custom_capture() {
+    result! {
+        @when  foo_err()
+        @error (e) => { let _ = e; }
+        @panic "could not foo: {}", e
+    }
+}
+
+#[test]
+fn when_err_result_should_eval_error_then_panic() {
+    let errored = Cell::new(false);
+
+    let actual = catch_unwind(AssertUnwindSafe(|| {
+        result! {
+            @when  foo_err()
+            @error { errored.set(true); }
+            @panic "could not foo: {}", err
+        }
+    }));
+
+    let message = actual.unwrap_err().downcast::<String>().unwrap();
+
+    assert!(errored.get());
+    assert_eq!("could not foo: foo failed!", *message);
+}
+
+#[test]
+fn when_err_result_should_panic_at_macro_invocation() {
+    thread_local! {
+        static PANICKED_AT: Cell<Option<u32>> = const { Cell::new(None) };
+    }
+
+    let hook = panic::take_hook();
+
+    panic::set_hook(Box::new(|info| PANICKED_AT.with(|panicked_at| panicked_at.set(
+        info.location().map(|location| location.line())
+    ))));
+
+    let expected = line!() + 2;
+    let actual = catch_unwind(|| {
+        result! {
+            @when  foo_err()
+            @panic "could not foo: {}", err
+        }
+    });
+
+    panic::set_hook(hook);
+
+    assert!(actual.is_err());
+    assert_eq!(Some(expected), PANICKED_AT.with(Cell::get));
+}
+
+#[test]
+fn when_ok_result_expression_should_not_panic() {
+    let expected = 42;
+
+    let actual = result! {
+        @ok    foo_ok()
+        @panic "could not foo: {}", err
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[should_panic(expected = "could not foo: foo failed!")]
+fn when_err_result_expression_should_panic() {
+    let _actual = result! {
+        @ok    foo_err()
+        @panic "could not foo: {}", err
+    };
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
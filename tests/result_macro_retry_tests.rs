@@ -0,0 +1,126 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use cfg_if::cfg_if;
+use test_toolbox::capture;
+#[cfg(feature = "result-debug")]
+use test_toolbox::expect;
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_err_result_then_ok_should_retry_and_eval_ok() {
+    let calls = Cell::new(0);
+    let mut actual = 0;
+
+    result! {
+        @when  flaky(&calls, 2)
+        @ok    (value) => { actual = value; }
+        @error { actual = 0; }
+        @retry 3
+    }
+
+    assert_eq!(42, actual);
+    assert_eq!(3, calls.get());
+}
+
+#[test]
+fn when_err_result_should_retry_and_eval_error_once() {
+    let calls = Cell::new(0);
+    let mut errors = 0;
+
+    result! {
+        @when  flaky(&calls, 5)
+        @error { errors += 1; }
+        @retry 3
+    }
+
+    assert_eq!(1, errors);
+    assert_eq!(3, calls.get());
+}
+
+#[test]
+fn when_err_result_should_retry_and_output_dbg_per_attempt() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! {
+                expected_stdout = "",
+                "attempt 1: foo failed!\\nattempt 2: foo failed!\\nattempt 3: foo failed!\\n"
+            }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    let calls = Cell::new(0);
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  flaky(&calls, 5)
+            @debug "attempt {}: {}", attempt, err
+            @error "err: {}", err
+            @retry 3
+        }
+    };
+
+    assert_eq!(expected_stdout.replace("\\n", "\n"), actual_stdout);
+    assert_eq!("err: foo failed!\n", actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_retry_with_backoff() {
+    let calls = Cell::new(0);
+    let started = Instant::now();
+
+    result! {
+        @when  flaky(&calls, 5)
+        @error "err: {}", err
+        @retry 3, backoff = Duration::from_millis(10 * attempt as u64)
+    }
+
+    assert_eq!(3, calls.get());
+    assert!(started.elapsed() >= Duration::from_millis(30));
+}
+
+#[test]
+fn when_tried_err_result_should_retry_and_return_err() {
+    let calls = Cell::new(0);
+    let actual = test_try(&calls);
+
+    assert_eq!(Err("foo failed!"), actual);
+    assert_eq!(2, calls.get());
+
+    fn test_try(calls: &Cell<usize>) -> TestResult {
+        result! {
+            @when  flaky(calls, 5)?
+            @retry 2
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_err_result_expression_should_retry_and_return_ok() {
+    let calls = Cell::new(0);
+
+    let actual = result! {
+        @ok    flaky(&calls, 1)
+        @error 0
+        @retry 2
+    };
+
+    assert_eq!(42, actual);
+    assert_eq!(2, calls.get());
+}
+
+fn flaky(calls: &Cell<usize>, failures: usize) -> TestResult {
+    calls.set(calls.get() + 1);
+
+    if calls.get() > failures { Ok(42) } else { Err("foo failed!") }
+}
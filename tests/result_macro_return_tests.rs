@@ -0,0 +1,131 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use std::process::ExitCode;
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_err_result_should_eval_error_then_return_value() {
+    let mut errors = 0;
+
+    assert!(!test_return(&mut errors));
+    assert_eq!(1, errors);
+
+    fn test_return(errors: &mut usize) -> bool {
+        result! {
+            @when   foo_err()
+            @error  { *errors += 1; }
+            @return false
+        }
+
+        true
+    }
+}
+
+#[test]
+fn when_ok_result_should_not_return() {
+    assert_eq!(vec![42], test_return());
+
+    fn test_return() -> Vec<usize> {
+        let mut actual = Vec::new();
+
+        result! {
+            @when   foo_ok()
+            @ok     (value) => { actual.push(value); }
+            @return Vec::new()
+        }
+
+        actual
+    }
+}
+
+#[test]
+fn when_err_result_should_return_from_unit_function() {
+    let mut actual = Vec::new();
+
+    test_return(&mut actual);
+
+    assert_eq!(vec!["error"], actual);
+
+    fn test_return(actual: &mut Vec<&'static str>) {
+        result! {
+            @when   foo_err()
+            @error  { actual.push("error"); }
+            @return
+        }
+
+        actual.push("not reached");
+    }
+}
+
+#[test]
+fn when_err_result_should_return_value_using_err() {
+    assert_eq!(ExitCode::from(11), test_return());
+
+    fn test_return() -> ExitCode {
+        result! {
+            @when   foo_err()
+            @return ExitCode::from(u8::try_from(err.len()).unwrap())
+        }
+
+        ExitCode::SUCCESS
+    }
+}
+
+#[test]
+fn when_err_result_should_return_value_using_inline_err() {
+    assert_eq!("failed: foo failed!", test_return());
+
+    fn test_return() -> String {
+        result! {
+            @when   foo_err()
+            @return format!("failed: {err}")
+        }
+
+        String::new()
+    }
+}
+
+#[test]
+fn when_err_result_should_eval_finally_then_return_value() {
+    let mut finalized = false;
+
+    assert_eq!(0, test_return(&mut finalized));
+    assert!(finalized);
+
+    fn test_return(finalized: &mut bool) -> usize {
+        result! {
+            @when    foo_err()
+            @return  0
+            @finally *finalized = true
+        }
+
+        1
+    }
+}
+
+#[test]
+fn when_err_result_expression_should_return_value() {
+    assert_eq!(0, test_return());
+
+    fn test_return() -> usize {
+        let value = result! {
+            @ok     foo_err()
+            @return 0
+        };
+
+        value + 1
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "result")]
+
 use cfg_if::cfg_if;
 use test_toolbox::capture;
 #[cfg(feature = "result-debug")]
@@ -344,10 +346,169 @@ fn when_mut_reference_result_should_evaluate_ok() {
     fn foo_ok_ref(value: &mut usize) -> Result<&mut usize, &'static str> { Ok(value) }
 }
 
+#[test]
+fn when_err_result_should_output_named_argument_message() {
+    let expected_stderr = "err: foo failed! (-42)\n";
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @error "err: {reason} ({code})", reason = err, code = -42
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_should_output_inline_captured_err() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! { expected_stdout = "", "dbg: \"foo failed!\"\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    let expected_stderr = "err: foo failed!\n";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @ok    "this will not output: {ok:?}"
+            @debug "dbg: {err:?}"
+            @error "err: {err}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_ok_result_should_output_inline_captured_ok() {
+    let expected_stdout = "ok:     42|42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when  foo_ok()
+            @ok    "ok: {ok:>width$}|{ok}", width = 6
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_result_should_output_inline_captured_custom_ok() {
+    let expected_stdout = "ok: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when  foo_ok()
+            @ok    (foo) => "ok: {foo}"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_result_should_output_destructured_tuple_ok() {
+    let expected_stdout = "ok: foo.txt (42)\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @when  foo_tuple_ok()
+            @ok    ((name, size)) => "ok: {name} ({size})"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
+#[test]
+fn when_ok_result_should_eval_destructured_slice_ok() {
+    let expected = (1, 3);
+    let mut actual = (0, 0);
+
+    result! {
+        @when  foo_array_ok()
+        @ok    ([first, .., last]) => { actual = (first, last); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_ok_result_should_eval_ref_mut_captured_ok() {
+    let expected = vec![1, 2, 3, 4];
+    let mut actual = Vec::new();
+
+    result! {
+        @when  foo_vec_ok()
+        @ok    (ref mut values) => { values.push(4); actual = values.clone(); }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_err_result_with_sections_in_any_order_should_output_dbg_and_err() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! { expected_stdout = "", "dbg: \"foo failed!\"\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    let expected_stderr = "err: foo failed!\n";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @error "err: {}", err
+            @debug "dbg: {:?}", err
+            @when  foo_err()
+            @ok    "this will not output: {:?}", ok
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_ok_result_with_ok_before_when_should_output_ok() {
+    let expected_stdout = "ok: 42\n";
+
+    let (actual_stdout, _actual_stderr) = capture! {
+        result! {
+            @ok    "ok: {}", ok
+            @error "this will not output: {}", err
+            @when  foo_ok()
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+}
+
 fn foo_ok() -> Result<usize, &'static str> {
     Ok(42)
 }
 
 fn foo_err() -> Result<(), &'static str> {
     Err("foo failed!")
-}
\ No newline at end of file
+}
+
+fn foo_tuple_ok() -> Result<(&'static str, usize), &'static str> {
+    Ok(("foo.txt", 42))
+}
+
+fn foo_array_ok() -> Result<[usize; 3], &'static str> {
+    Ok([1, 2, 3])
+}
+
+fn foo_vec_ok() -> Result<Vec<usize>, &'static str> {
+    Ok(vec![1, 2, 3])
+}
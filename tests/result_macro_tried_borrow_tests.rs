@@ -0,0 +1,92 @@
+#![cfg(feature = "result")]
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, String>;
+
+#[test]
+fn when_tried_err_with_error_expr_should_borrow_and_return_err() {
+    let expected = Err(String::from("foo failed!"));
+    let mut errors = Vec::new();
+    let actual = test_try(&mut errors);
+
+    assert_eq!(expected, actual);
+    assert_eq!(vec![String::from("foo failed!")], errors);
+
+    fn test_try(errors: &mut Vec<String>) -> TestResult {
+        result! {
+            @when  foo_err()?
+            @error { consume(errors, err); }
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_with_ok_and_error_expr_should_borrow_and_return_err() {
+    let expected = Err(String::from("foo failed!"));
+    let mut errors = Vec::new();
+    let actual = test_try(&mut errors);
+
+    assert_eq!(expected, actual);
+    assert_eq!(vec![String::from("foo failed!")], errors);
+
+    fn test_try(errors: &mut Vec<String>) -> TestResult {
+        result! {
+            @when  foo_err()?
+            @ok    "this will not output: {:?}", ok
+            @error { consume(errors, err); }
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_with_custom_capture_should_borrow_and_return_err() {
+    let expected = Err(String::from("foo failed!"));
+    let mut errors = Vec::new();
+    let actual = test_try(&mut errors);
+
+    assert_eq!(expected, actual);
+    assert_eq!(vec![String::from("foo failed!")], errors);
+
+    fn test_try(errors: &mut Vec<String>) -> TestResult {
+        result! {
+            @when  foo_err()?
+            @debug (e) => "dbg: {}", e
+            @error { consume(errors, e); }
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_tried_err_with_error_arms_should_borrow_and_return_err() {
+    let expected = Err(String::from("foo failed!"));
+    let mut errors = Vec::new();
+    let actual = test_try(&mut errors);
+
+    assert_eq!(expected, actual);
+    assert_eq!(vec![String::from("foo failed!")], errors);
+
+    fn test_try(errors: &mut Vec<String>) -> TestResult {
+        result! {
+            @when  foo_err()?
+            @error (e) if e.is_empty() => "this will not output"
+            @error (e) => { consume(errors, e); }
+        }
+
+        Ok(0)
+    }
+}
+
+fn consume(errors: &mut Vec<String>, err: &str) {
+    errors.push(String::from(err));
+}
+
+fn foo_err() -> TestResult {
+    Err(String::from("foo failed!"))
+}
@@ -1,5 +1,8 @@
+#![cfg(feature = "result")]
 #![allow(unused_assignments)]
 
+use std::error::Error;
+
 use cfg_if::cfg_if;
 use test_toolbox::capture;
 #[cfg(feature = "result-debug")]
@@ -473,6 +476,83 @@ fn when_ok_result_should_output_ok() {
     }
 }
 
+#[test]
+fn when_err_result_should_convert_err_into_return_type() {
+    let expected = Err(String::from("foo failed!"));
+    let expected_stderr = "err: \"foo failed!\"\n";
+
+    let (actual, actual_stderr) = {
+        let mut actual = Ok(0);
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            actual = test_try()
+        };
+
+        (actual, actual_stderr)
+    };
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected_stderr, actual_stderr);
+
+    fn test_try() -> Result<usize, String> {
+        result! {
+            @when  foo_err()?
+            @error "err: {:?}", err
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_err_result_with_ok_should_convert_err_into_boxed_error() {
+    let expected = "foo failed!";
+    let actual = test_try().unwrap_err().to_string();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Result<usize, Box<dyn Error>> {
+        result! {
+            @when  foo_err()?
+            @ok    "this will not output: {:?}", ok
+        }
+
+        Ok(0)
+    }
+}
+
+#[test]
+fn when_err_result_with_ok_should_convert_err_into_custom_error() {
+    let expected = Err(FooError::Failed("foo failed!"));
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> Result<usize, FooError> {
+        let (_actual_stdout, _actual_stderr) = capture! {
+            result! {
+                @when  foo_err()?
+                @ok    (foo) => "this will not output: {:?}", foo
+                @debug "dbg: {:?}", err
+                @error "err: {:?}", err
+            }
+        };
+
+        Ok(0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum FooError {
+    Failed(&'static str),
+}
+
+impl From<&'static str> for FooError {
+    fn from(err: &'static str) -> Self {
+        Self::Failed(err)
+    }
+}
+
 fn foo_ok() -> TestResult {
     Ok(42)
 }
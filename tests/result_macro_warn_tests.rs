@@ -0,0 +1,141 @@
+#![cfg(feature = "result")]
+#![allow(unused_assignments)]
+
+use cfg_if::cfg_if;
+use test_toolbox::capture;
+#[cfg(feature = "result-debug")]
+use test_toolbox::expect;
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+fn when_err_result_with_warn_should_output_warn() {
+    cfg_if! {
+        if #[cfg(feature = "result-warn")] {
+            let expected_stderr = "warning: foo failed!\n";
+        } else {
+            let expected_stderr = "";
+        }
+    }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @warn  "{}", err
+        }
+    };
+
+    assert_eq!("", actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_ok_result_with_warn_should_not_output_warn() {
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_ok()
+            @ok    "ok: {}", ok
+            @warn  "{}", err
+        }
+    };
+
+    assert_eq!("ok: 42\n", actual_stdout);
+    assert_eq!("", actual_stderr);
+}
+
+#[test]
+fn when_err_result_with_warn_and_custom_capture_should_output_warn() {
+    cfg_if! {
+        if #[cfg(feature = "result-warn")] {
+            let expected_stderr = "warning: foo failed!\n";
+        } else {
+            let expected_stderr = "";
+        }
+    }
+
+    let (_actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @warn  (e) => "{e}"
+        }
+    };
+
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_err_result_with_debug_warn_and_error_should_output_all() {
+    cfg_if! {
+        if #[cfg(feature = "result-debug")] {
+            expect! { expected_stdout = "", "dbg: foo failed!\n" }
+        } else {
+            let expected_stdout = "";
+        }
+    }
+
+    cfg_if! {
+        if #[cfg(feature = "result-warn")] {
+            let expected_stderr = "warning: foo failed!\nerr: foo failed!\n";
+        } else {
+            let expected_stderr = "err: foo failed!\n";
+        }
+    }
+
+    let expected = "foo failed!";
+    let mut actual = "";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        result! {
+            @when  foo_err()
+            @ok    "this will not output: {}", ok
+            @debug "dbg: {}", err
+            @warn  "{}", err
+            @error "err: {}", err;
+                   actual = err
+        }
+    };
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_tried_err_result_with_warn_should_output_warn_and_return_err() {
+    let expected = Err("foo failed!");
+    let actual = test_try();
+
+    assert_eq!(expected, actual);
+
+    fn test_try() -> TestResult {
+        cfg_if! {
+            if #[cfg(feature = "result-warn")] {
+                let expected_stderr = "warning: foo failed!\n";
+            } else {
+                let expected_stderr = "";
+            }
+        }
+
+        let (_actual_stdout, actual_stderr) = capture! {
+            result! {
+                @when  foo_err()?
+                @warn  "{}", err
+            }
+        };
+
+        assert_eq!(expected_stderr, actual_stderr);
+
+        Ok(0)
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
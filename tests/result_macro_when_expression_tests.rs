@@ -0,0 +1,234 @@
+#![cfg(feature = "result")]
+
+use test_toolbox::capture;
+
+use macrofied_toolbox::result;
+
+type TestResult = Result<usize, &'static str>;
+
+#[test]
+#[allow(clippy::redundant_closure, clippy::redundant_closure_call)]
+fn when_closure_call_should_eval_ok() {
+    let expected = 42;
+    let mut actual = 0;
+
+    result! {
+        @when  (|| foo_ok())()
+        @ok    (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[allow(clippy::never_loop)]
+fn when_loop_should_eval_ok() {
+    let expected = 42;
+    let mut actual = 0;
+
+    result! {
+        @when  loop { break foo_ok(); }
+        @ok    (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_paren_should_eval_err() {
+    let expected = "foo failed!";
+    let mut actual = "";
+
+    result! {
+        @when  (foo_err());
+        @error { actual = err; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_paren_with_try_should_eval_inner_result() {
+    let expected = Ok("foo failed!");
+    let actual = test_paren();
+
+    assert_eq!(expected, actual);
+
+    fn test_paren() -> Result<&'static str, &'static str> {
+        let mut actual = "";
+
+        result! {
+            @when  (nested()?);
+            @error { actual = err; }
+        }
+
+        Ok(actual)
+    }
+}
+
+#[test]
+fn when_tuple_field_should_eval_err() {
+    let expected = "foo failed!";
+    let mut actual = "";
+    let pair = (foo_ok(), foo_err());
+
+    result! {
+        @when  pair.1;
+        @error { actual = err; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_unary_should_eval_ok() {
+    let expected = 42;
+    let mut actual = 0;
+    let result = &foo_ok();
+
+    result! {
+        @when  *result;
+        @ok    (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_unsafe_block_should_eval_ok() {
+    let expected = 42;
+    let mut actual = 0;
+
+    result! {
+        @when  unsafe { foo_unsafe() };
+        @ok    (value) => { actual = value; }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_deref_with_only_error_should_eval_error() {
+    let result = foo_err();
+    let r = &result;
+
+    let (_, actual_stderr) = capture! {
+        result! {
+            @when  *r;
+            @error "failed"
+        }
+    };
+
+    assert_eq!("failed\n", actual_stderr);
+}
+
+#[test]
+fn when_paren_deref_with_only_error_should_eval_error() {
+    let result = foo_err();
+    let r = &result;
+
+    let (_, actual_stderr) = capture! {
+        result! {
+            @when  (*r);
+            @error "failed"
+        }
+    };
+
+    assert_eq!("failed\n", actual_stderr);
+}
+
+#[test]
+fn when_deref_with_only_ok_message_should_eval_ok() {
+    let result = foo_ok();
+    let r = &result;
+
+    let (actual_stdout, _) = capture! {
+        result! {
+            @when *r;
+            @ok   "succeeded"
+        }
+    };
+
+    assert_eq!("succeeded\n", actual_stdout);
+}
+
+#[test]
+fn when_paren_deref_with_only_ok_message_should_eval_ok() {
+    let result = foo_ok();
+    let r = &result;
+
+    let (actual_stdout, _) = capture! {
+        result! {
+            @when (*r);
+            @ok   "succeeded"
+        }
+    };
+
+    assert_eq!("succeeded\n", actual_stdout);
+}
+
+#[test]
+fn when_paren_struct_literal_should_eval_ok() {
+    let expected = 42;
+    let mut actual = 0;
+
+    result! {
+        @when  (Wrapper { result: foo_ok() }.result)
+        @ok    (value) => { actual = value; }
+        @error "failed"
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_paren_struct_literal_with_retry_should_eval_error() {
+    let expected = "foo failed!";
+    let mut actual = "";
+    let mut calls = 0;
+
+    result! {
+        @when  (Wrapper { result: { calls += 1; foo_err() } }.result)
+        @error { actual = err; }
+        @retry 3
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(3, calls);
+}
+
+#[test]
+fn when_paren_struct_literal_result_expression_should_map_value() {
+    let expected = String::from("42");
+
+    let actual = result! {
+        @ok    (Wrapper { result: foo_ok() }.result)
+        @map   |value| value.to_string()
+        @error String::new()
+    };
+
+    assert_eq!(expected, actual);
+}
+
+struct Wrapper {
+    result: TestResult,
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn foo_ok() -> TestResult {
+    Ok(42)
+}
+
+const fn foo_err() -> TestResult {
+    Err("foo failed!")
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const unsafe fn foo_unsafe() -> TestResult {
+    Ok(42)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+const fn nested() -> Result<TestResult, &'static str> {
+    Ok(Err("foo failed!"))
+}
@@ -1,3 +1,5 @@
+#![cfg(all(feature = "option", feature = "result"))]
+
 use trybuild::TestCases;
 
 #[test]